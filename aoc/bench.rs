@@ -0,0 +1,104 @@
+//! Repeated runs of days with min/median/max timings.
+
+use aoc2017::registry::{Day, Part};
+use std::error;
+use std::time::Duration;
+
+use crate::json::Value;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        }
+    }
+
+    pub fn to_json(self) -> Value {
+        Value::Object(vec![
+            ("min_ns", Value::Number(self.min.as_nanos())),
+            ("median_ns", Value::Number(self.median.as_nanos())),
+            ("max_ns", Value::Number(self.max.as_nanos())),
+        ])
+    }
+}
+
+pub struct Bench {
+    pub day: &'static Day,
+    pub parse: Stats,
+    // `None` if the day has no such part.
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Bench {
+    /// Sum of the medians, used to find the slowest days.
+    pub fn median_total(&self) -> Duration {
+        [Some(self.parse), self.part1, self.part2]
+            .iter()
+            .flatten()
+            .map(|stats| stats.median)
+            .sum()
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("day", Value::Number(self.day.number.into())),
+            ("title", self.day.title.into()),
+            ("parse", self.parse.to_json()),
+            ("part1", self.part1.map(Stats::to_json).into()),
+            ("part2", self.part2.map(Stats::to_json).into()),
+            (
+                "median_total_ns",
+                Value::Number(self.median_total().as_nanos()),
+            ),
+        ])
+    }
+}
+
+/// Runs parse and both parts of `day` on `input` `runs` times.
+pub fn bench(day: &'static Day, input: &str, runs: usize) -> Result<Bench, Box<dyn error::Error>> {
+    assert!(runs > 0, "at least one run is needed");
+
+    let mut parse = vec![];
+    let mut parts = [vec![], vec![]];
+
+    for _ in 0..runs {
+        let report = (day.run)(input, &[Part::One, Part::Two])?;
+
+        parse.push(report.parse_time);
+        for answer in &report.answers {
+            let idx = match answer.part {
+                Part::One => 0,
+                Part::Two => 1,
+            };
+            parts[idx].push(answer.time);
+        }
+    }
+
+    let [part1, part2] = parts.map(|samples| (!samples.is_empty()).then(|| Stats::new(samples)));
+
+    Ok(Bench {
+        day,
+        parse: Stats::new(parse),
+        part1,
+        part2,
+    })
+}
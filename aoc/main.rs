@@ -0,0 +1,393 @@
+mod bench;
+mod json;
+mod new;
+
+use aoc2017::registry::{self, Day, Part, Report};
+use std::cmp::Reverse;
+use std::env;
+use std::error;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "\
+usage:
+    aoc list                                    list all days with their titles
+    aoc new <day> <title>                       scaffold and register a new day
+    aoc run <day> [--part <1|2>] [--input <path>|-] [--format <text|json>]
+                                                solve a day, `-` reads input from stdin
+    aoc run [--format <text|json>]              solve every day, print a summary table
+    aoc bench [<day>] [--runs <n>] [--json <path>|-]
+                                                time every part of every day `n` times,
+                                                slowest days first, optionally save as json,
+                                                `-` prints json and moves the table to stderr
+
+input defaults to `dayNN/input.txt` relative to the current directory.
+json output is `{day, title, part1, part2, timings, error}` per day, an array for every day.";
+
+const DEFAULT_RUNS: usize = 5;
+
+fn read_input(path: &str) -> Result<String, Box<dyn error::Error>> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|err| format!("can't read {path}: {err}").into())
+    }
+}
+
+fn default_input(day: &Day) -> String {
+    format!("{}/input.txt", day.dir())
+}
+
+fn parse_day(arg: &str) -> Result<&'static Day, Box<dyn error::Error>> {
+    let number: u8 = arg.parse().map_err(|_| format!("invalid day: {arg}"))?;
+    let day = registry::find(number).ok_or(format!("no such day: {number}"))?;
+    Ok(day)
+}
+
+fn parse_part(arg: &str) -> Result<Part, Box<dyn error::Error>> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        part => Err(format!("invalid part: {part}"))?,
+    }
+}
+
+fn parse_runs(arg: &str) -> Result<usize, Box<dyn error::Error>> {
+    match arg.parse() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(format!("invalid number of runs: {arg}"))?,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn parse_format(arg: &str) -> Result<Format, Box<dyn error::Error>> {
+    match arg {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        format => Err(format!("invalid format: {format}"))?,
+    }
+}
+
+fn list() {
+    for day in registry::DAYS {
+        println!("{:>2}  {}", day.number, day.title);
+    }
+}
+
+/// Solves requested `parts`, a single requested part must exist.
+fn solve(day: &Day, parts: &[Part], input_file: &str) -> Result<Report, Box<dyn error::Error>> {
+    let input = read_input(input_file)?;
+    let report = (day.run)(&input, parts)?;
+
+    // both parts are requested by default
+    if let [part] = parts
+        && report.answer(*part).is_none()
+    {
+        Err(format!("day {} has no part {part}", day.number))?;
+    }
+
+    Ok(report)
+}
+
+/// `{day, title, part1, part2, timings, error}`, timings are in nanoseconds.
+fn report_to_json(day: &Day, report: &Result<Report, Box<dyn error::Error>>) -> json::Value {
+    let (answers, timings, error) = match report {
+        Ok(report) => {
+            let answers = [Part::One, Part::Two].map(|part| {
+                report
+                    .answer(part)
+                    .map(|answer| answer.answer.as_str())
+                    .into()
+            });
+            let [part1_ns, part2_ns] = [Part::One, Part::Two].map(|part| {
+                report
+                    .answer(part)
+                    .map(|answer| answer.time.as_nanos())
+                    .into()
+            });
+            let timings = json::Value::Object(vec![
+                ("parse_ns", report.parse_time.as_nanos().into()),
+                ("part1_ns", part1_ns),
+                ("part2_ns", part2_ns),
+                ("total_ns", report.total_time().as_nanos().into()),
+            ]);
+
+            (answers, timings, json::Value::Null)
+        }
+        Err(err) => (
+            [json::Value::Null, json::Value::Null],
+            json::Value::Null,
+            err.to_string().as_str().into(),
+        ),
+    };
+    let [part1, part2] = answers;
+
+    json::Value::Object(vec![
+        ("day", u128::from(day.number).into()),
+        ("title", day.title.into()),
+        ("part1", part1),
+        ("part2", part2),
+        ("timings", timings),
+        ("error", error),
+    ])
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    input_file: &str,
+    format: Format,
+) -> Result<(), Box<dyn error::Error>> {
+    if format == Format::Json {
+        let report = solve(day, parts, input_file);
+        println!("{}", report_to_json(day, &report));
+
+        if report.is_err() {
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    println!("--- Day {}: {} ---", day.number, day.title);
+
+    let report = solve(day, parts, input_file)?;
+
+    for answer in &report.answers {
+        println!("{}", answer.answer);
+    }
+
+    Ok(())
+}
+
+fn run_all(format: Format) {
+    if format == Format::Json {
+        let reports = registry::DAYS
+            .iter()
+            .map(|day| {
+                let report = solve(day, &[Part::One, Part::Two], &default_input(day));
+                report_to_json(day, &report)
+            })
+            .collect();
+        println!("{}", json::Value::Array(reports));
+        return;
+    }
+
+    println!(
+        "{:>3}  {:<40}  {:>32}  {:>32}  {:>10}",
+        "day", "title", "part1", "part2", "time"
+    );
+
+    let mut total = Duration::ZERO;
+    let mut errors = vec![];
+
+    for day in registry::DAYS {
+        let report = solve(day, &[Part::One, Part::Two], &default_input(day));
+
+        let (answers, elapsed) = match report {
+            Ok(report) => {
+                let answers = [Part::One, Part::Two].map(|part| match report.answer(part) {
+                    Some(answer) => answer.answer.clone(),
+                    None => "-".to_string(),
+                });
+                (answers, report.total_time())
+            }
+            Err(err) => {
+                errors.push(format!("day {}: {err}", day.number));
+                (["error".to_string(), "error".to_string()], Duration::ZERO)
+            }
+        };
+
+        total += elapsed;
+
+        println!(
+            "{:>3}  {:<40}  {:>32}  {:>32}  {:>10.3?}",
+            day.number, day.title, answers[0], answers[1], elapsed
+        );
+    }
+
+    println!("{:>125.3?}", total);
+
+    for error in errors {
+        eprintln!("{error}");
+    }
+}
+
+fn bench(
+    days: &[&'static Day],
+    runs: usize,
+    json_file: Option<&str>,
+) -> Result<(), Box<dyn error::Error>> {
+    let mut benches = vec![];
+    let mut errors = vec![];
+
+    for day in days {
+        let result =
+            read_input(&default_input(day)).and_then(|input| bench::bench(day, &input, runs));
+
+        match result {
+            Ok(bench) => benches.push(bench),
+            Err(err) => errors.push(format!("day {}: {err}", day.number)),
+        }
+    }
+
+    // slowest days first.
+    benches.sort_by_key(|bench| Reverse(bench.median_total()));
+
+    let mut table = format!(
+        "{:>3}  {:<40}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+        "day", "title", "stage", "min", "median", "max"
+    );
+
+    for bench in &benches {
+        let stages = [
+            ("parse", Some(bench.parse)),
+            ("part1", bench.part1),
+            ("part2", bench.part2),
+        ];
+
+        for (idx, (stage, stats)) in stages.into_iter().enumerate() {
+            let (number, title) = match idx {
+                0 => (bench.day.number.to_string(), bench.day.title),
+                _ => (String::new(), ""),
+            };
+            let [min, median, max] = match stats {
+                Some(stats) => {
+                    [stats.min, stats.median, stats.max].map(|time| format!("{time:.3?}"))
+                }
+                None => ["-", "-", "-"].map(String::from),
+            };
+
+            table.push_str(&format!(
+                "{:>3}  {:<40}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+                number, title, stage, min, median, max
+            ));
+        }
+    }
+
+    // json on stdout must stay parsable
+    if json_file == Some("-") {
+        eprint!("{table}");
+    } else {
+        print!("{table}");
+    }
+
+    if let Some(json_file) = json_file {
+        let json = json::Value::Object(vec![
+            ("runs", json::Value::Number(runs as u128)),
+            (
+                "days",
+                json::Value::Array(benches.iter().map(bench::Bench::to_json).collect()),
+            ),
+        ]);
+
+        if json_file == "-" {
+            println!("{json}");
+        } else {
+            fs::write(json_file, format!("{json}\n"))
+                .map_err(|err| format!("can't write {json_file}: {err}"))?;
+        }
+    }
+
+    for error in errors {
+        eprintln!("{error}");
+    }
+
+    Ok(())
+}
+
+fn try_main() -> Result<(), Box<dyn error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("new") => match &args[1..] {
+            [day, title] => {
+                let number: u8 = day.parse().map_err(|_| format!("invalid day: {day}"))?;
+                new::new_day(number, title)?;
+            }
+            _ => Err("usage: aoc new <day> <title>")?,
+        },
+        Some("run") => {
+            let mut options = args[1..].iter().peekable();
+            let day = match options.next_if(|arg| !arg.starts_with("--")) {
+                Some(day) => Some(parse_day(day)?),
+                None => None,
+            };
+
+            let mut parts = vec![Part::One, Part::Two];
+            let mut input_file = None;
+            let mut format = Format::Text;
+
+            while let Some(option) = options.next() {
+                let value = options
+                    .next()
+                    .ok_or(format!("missing value for {option}"))?;
+
+                match option.as_str() {
+                    "--part" => parts = vec![parse_part(value)?],
+                    "--input" => input_file = Some(value.to_string()),
+                    "--format" => format = parse_format(value)?,
+                    option => Err(format!("unknown option: {option}"))?,
+                }
+            }
+
+            match day {
+                Some(day) => {
+                    let input_file = input_file.unwrap_or_else(|| default_input(day));
+                    run_day(day, &parts, &input_file, format)?;
+                }
+                None if parts.len() == 1 || input_file.is_some() => {
+                    Err("--part and --input need a day")?
+                }
+                None => run_all(format),
+            }
+        }
+        Some("bench") => {
+            let mut days: Vec<&Day> = registry::DAYS.iter().collect();
+            let mut runs = DEFAULT_RUNS;
+            let mut json_file = None;
+
+            let mut options = args[1..].iter().peekable();
+            if let Some(day) = options.next_if(|arg| !arg.starts_with("--")) {
+                days = vec![parse_day(day)?];
+            }
+
+            while let Some(option) = options.next() {
+                let value = options
+                    .next()
+                    .ok_or(format!("missing value for {option}"))?;
+
+                match option.as_str() {
+                    "--runs" => runs = parse_runs(value)?,
+                    "--json" => json_file = Some(value.as_str()),
+                    option => Err(format!("unknown option: {option}"))?,
+                }
+            }
+
+            bench(&days, runs, json_file)?;
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    // `Result` from `main` prints errors with `Debug`, parse errors read better with `Display`.
+    if let Err(err) = try_main() {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
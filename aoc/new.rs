@@ -0,0 +1,123 @@
+//! Scaffolding of a new day, run from the workspace root.
+
+use std::error;
+use std::fs;
+use std::path::Path;
+
+const DAY_MODULE: &str = include_str!("templates/day.rs.tmpl");
+const MAIN: &str = include_str!("templates/main.rs.tmpl");
+const MANIFEST: &str = include_str!("templates/Cargo.toml.tmpl");
+const MAKEFILE: &str = include_str!("templates/Makefile.tmpl");
+const ANSWERS: &str = include_str!("templates/answers.toml.tmpl");
+
+const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+const LIB: &str = "aoc2017/src/lib.rs";
+const REGISTRY: &str = "aoc2017/src/registry.rs";
+
+fn render(template: &str, number: u8, title: &str) -> String {
+    template
+        .replace("{{day}}", &format!("day{number:02}"))
+        .replace("{{Day}}", &format!("Day{number:02}"))
+        .replace("{{number}}", &number.to_string())
+        .replace("{{title}}", &title.escape_debug().to_string())
+}
+
+/// Content of `path` with `entry` inserted among the lines for which `is_entry` holds,
+/// keeping them sorted.
+fn with_entry(
+    path: &str,
+    entry: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> Result<String, Box<dyn error::Error>> {
+    let content = fs::read_to_string(path).map_err(|err| format!("can't read {path}: {err}"))?;
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|&idx| is_entry(lines[idx]))
+        .collect();
+    let last = *entries
+        .last()
+        .ok_or(format!("no days are listed in {path}"))?;
+
+    if entries.iter().any(|&idx| lines[idx] == entry) {
+        Err(format!("{path} already lists `{}`", entry.trim()))?;
+    }
+
+    // day numbers are zero-padded, so entries sort as strings.
+    let position = entries
+        .iter()
+        .find(|&&idx| lines[idx] > entry)
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(position, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write_new(path: &Path, content: &str) -> Result<(), Box<dyn error::Error>> {
+    fs::write(path, content).map_err(|err| format!("can't write {}: {err}", path.display()).into())
+}
+
+/// Creates `dayNN` with fixtures and a binary, `aoc2017/src/dayNN.rs` from the template,
+/// and adds the day to the workspace, the library and the registry.
+pub fn new_day(number: u8, title: &str) -> Result<(), Box<dyn error::Error>> {
+    if !(1..=99).contains(&number) {
+        Err(format!("invalid day: {number}"))?;
+    }
+    if !Path::new(LIB).exists() || !Path::new(REGISTRY).exists() {
+        Err("aoc new must be run from the workspace root")?;
+    }
+
+    let day = format!("day{number:02}");
+    let dir = Path::new(&day);
+    let module = Path::new("aoc2017/src").join(format!("{day}.rs"));
+
+    if dir.exists() {
+        Err(format!("{day} already exists"))?;
+    }
+    if module.exists() {
+        Err(format!("{} already exists", module.display()))?;
+    }
+
+    // every registration is checked before anything is written
+    let registrations = [
+        (
+            WORKSPACE_MANIFEST,
+            with_entry(WORKSPACE_MANIFEST, &format!("    \"{day}\","), |line| {
+                line.starts_with("    \"day")
+            })?,
+        ),
+        (
+            LIB,
+            with_entry(LIB, &format!("pub mod {day};"), |line| {
+                line.starts_with("pub mod day")
+            })?,
+        ),
+        (
+            REGISTRY,
+            with_entry(
+                REGISTRY,
+                &format!("    day!({day}::Day{number:02}),"),
+                |line| line.starts_with("    day!(day"),
+            )?,
+        ),
+    ];
+
+    fs::create_dir(dir).map_err(|err| format!("can't create {day}: {err}"))?;
+
+    write_new(&dir.join("Cargo.toml"), &render(MANIFEST, number, title))?;
+    write_new(&dir.join("Makefile"), &render(MAKEFILE, number, title))?;
+    write_new(&dir.join("main.rs"), &render(MAIN, number, title))?;
+    write_new(&dir.join("answers.toml"), &render(ANSWERS, number, title))?;
+    write_new(&dir.join("input.txt"), "")?;
+    write_new(&dir.join("sample.txt"), "")?;
+    write_new(&module, &render(DAY_MODULE, number, title))?;
+
+    for (path, content) in registrations {
+        write_new(Path::new(path), &content)?;
+    }
+
+    println!("{day} initialized with title \"{title}\".");
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! `aoc bench --json -` keeps stdout parsable.
+
+use std::env;
+use std::fs;
+use std::process::{self, Command};
+
+#[test]
+fn json_on_stdout() {
+    let dir = env::temp_dir().join(format!("aoc-bench-{}", process::id()));
+    fs::create_dir_all(dir.join("day01")).unwrap();
+    fs::write(dir.join("day01").join("input.txt"), "1122\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "1", "--runs", "2", "--json", "-"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("{\"runs\":2,\"days\":[{\"day\":1,"));
+    assert!(stdout.ends_with("}]}\n"));
+    assert_eq!(stdout.lines().count(), 1);
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("day  title"));
+    assert!(stderr.contains("  1  Inverse Captcha"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,93 @@
+//! `aoc new` scaffolds a day and registers it, or touches nothing.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+
+const MANIFEST: &str =
+    "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+const LIB: &str = "pub mod day01;\npub mod day03;\npub mod registry;\n";
+const REGISTRY: &str =
+    "const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day03::Day03),\n];\n";
+
+/// Scratch workspace root with days 1 and 3 registered.
+fn workspace(name: &str, registry: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-new-{name}-{}", process::id()));
+    fs::create_dir_all(dir.join("aoc2017/src")).unwrap();
+    fs::write(dir.join("Cargo.toml"), MANIFEST).unwrap();
+    fs::write(dir.join("aoc2017/src/lib.rs"), LIB).unwrap();
+    fs::write(dir.join("aoc2017/src/registry.rs"), registry).unwrap();
+    dir
+}
+
+fn aoc(dir: &PathBuf, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+}
+
+fn read(dir: &Path, path: &str) -> String {
+    fs::read_to_string(dir.join(path)).unwrap()
+}
+
+#[test]
+fn registers_in_order() {
+    let dir = workspace("order", REGISTRY);
+
+    let output = aoc(&dir, &["new", "2", "Corruption Checksum"]);
+    assert!(output.status.success());
+
+    assert_eq!(
+        read(&dir, "Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n"
+    );
+    assert_eq!(
+        read(&dir, "aoc2017/src/lib.rs"),
+        "pub mod day01;\npub mod day02;\npub mod day03;\npub mod registry;\n"
+    );
+    assert!(
+        read(&dir, "aoc2017/src/registry.rs").contains(
+            "    day!(day01::Day01),\n    day!(day02::Day02),\n    day!(day03::Day03),\n"
+        )
+    );
+    assert!(read(&dir, "aoc2017/src/day02.rs").contains("Corruption Checksum"));
+    for file in [
+        "Cargo.toml",
+        "Makefile",
+        "main.rs",
+        "answers.toml",
+        "input.txt",
+    ] {
+        assert!(dir.join("day02").join(file).exists(), "{file}");
+    }
+
+    let output = aoc(&dir, &["new", "2", "Corruption Checksum"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn nothing_written_on_error() {
+    // the registry lists day 2 already, the other two don't
+    let registry = REGISTRY.replace("day03::Day03", "day02::Day02");
+    let dir = workspace("error", &registry);
+
+    let output = aoc(&dir, &["new", "2", "Corruption Checksum"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("aoc2017/src/registry.rs already lists `day!(day02::Day02),`")
+    );
+
+    assert_eq!(read(&dir, "Cargo.toml"), MANIFEST);
+    assert_eq!(read(&dir, "aoc2017/src/lib.rs"), LIB);
+    assert!(!dir.join("day02").exists());
+    assert!(!dir.join("aoc2017/src/day02.rs").exists());
+
+    fs::remove_dir_all(dir).unwrap();
+}
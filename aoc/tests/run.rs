@@ -0,0 +1,75 @@
+//! `aoc run --format json` reports failing days in the `error` field.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{self, Command, Output};
+
+const NO_DIVISIBLE_PAIR: &str = "5 9\n7 3\n";
+const BALANCED_TOWER: &str = "a (1) -> b, c\nb (5)\nc (5)\n";
+
+/// Scratch directory with only day 2 and 7 inputs, both unsolvable.
+fn workspace(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-{name}-{}", process::id()));
+    for (day, input) in [("day02", NO_DIVISIBLE_PAIR), ("day07", BALANCED_TOWER)] {
+        fs::create_dir_all(dir.join(day)).unwrap();
+        fs::write(dir.join(day).join("input.txt"), input).unwrap();
+    }
+    dir
+}
+
+fn aoc(dir: &PathBuf, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn single_day() {
+    let dir = workspace("single");
+
+    let output = aoc(&dir, &["run", "7", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"day\":7,\"title\":\"Recursive Circus\",\"part1\":null,\"part2\":null,\
+         \"timings\":null,\"error\":\"the tower is already balanced\"}\n"
+    );
+
+    let output = aoc(&dir, &["run", "2", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        json.contains(
+            "\"error\":\"row 1: no quotient checksum, there is no evenly divisible pair\""
+        )
+    );
+
+    // only the solvable part
+    let output = aoc(&dir, &["run", "7", "--part", "1", "--format", "json"]);
+    assert!(output.status.success());
+    let json = String::from_utf8(output.stdout).unwrap();
+    assert!(json.contains("\"part1\":\"a\"") && json.ends_with("\"error\":null}\n"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn every_day() {
+    let dir = workspace("every");
+
+    let output = aoc(&dir, &["run", "--format", "json"]);
+    let json = String::from_utf8(output.stdout).unwrap();
+    assert!(json.starts_with('[') && json.ends_with("]\n"));
+
+    let days: Vec<&str> = json.split("{\"day\":").skip(1).collect();
+    assert_eq!(days.len(), 25);
+    assert!(days[1].contains("\"error\":\"row 1: no quotient checksum"));
+    assert!(days[6].contains("\"error\":\"the tower is already balanced\""));
+    // the other inputs are missing
+    assert!(days[0].contains("\"error\":\"can't read day01/input.txt"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,96 @@
+//! Expected answers, stored in `answers.toml` next to the puzzle inputs.
+//!
+//! Only a tiny subset of TOML is understood: a table per input file,
+//! named after it without the `.txt` extension, with quoted answers of its parts.
+//!
+//! ```toml
+//! [sample]
+//! part1 = "18"
+//!
+//! [input]
+//! part1 = "45158"
+//! part2 = "294"
+//! ```
+
+use crate::ParseError;
+use crate::parse::{self, Token};
+use crate::registry::Part;
+
+/// Name of the manifest file in a day's directory.
+pub const FILE_NAME: &str = "answers.toml";
+
+/// Answers for a single input file, parts without an answer are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Fixture {
+    /// File with the puzzle input.
+    pub fn file_name(&self) -> String {
+        format!("{}.txt", self.name)
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        self.answers.iter().map(|(part, _)| *part).collect()
+    }
+}
+
+fn parse_answer(line: Token, fixture: &mut Fixture) -> Result<(), ParseError> {
+    let (key, value) = line
+        .text
+        .split_once('=')
+        .ok_or_else(|| line.error("`part1 = \"answer\"`"))?;
+
+    let key = line.slice(key.trim());
+    let part = match key.text {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _ => return Err(key.error("`part1` or `part2`")),
+    };
+    if fixture.parts().contains(&part) {
+        return Err(key.error(format!("single answer for part {part}")));
+    }
+
+    let value = line.slice(value.trim()).strip("\"", "\"")?;
+    fixture.answers.push((part, value.text.to_string()));
+
+    Ok(())
+}
+
+pub fn parse(manifest: &str) -> Result<Vec<Fixture>, ParseError> {
+    let mut fixtures: Vec<Fixture> = vec![];
+
+    for line in parse::lines(manifest) {
+        let line = line.slice(line.text.trim());
+
+        if line.text.is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+
+        if line.text.starts_with('[') {
+            let name = line.strip("[", "]")?;
+            let name = name.slice(name.text.trim());
+
+            if name.text.is_empty() {
+                return Err(name.error("name of the input file"));
+            }
+            if fixtures.iter().any(|fixture| fixture.name == name.text) {
+                return Err(name.error("input file without a table yet"));
+            }
+
+            fixtures.push(Fixture {
+                name: name.text.to_string(),
+                answers: vec![],
+            });
+        } else {
+            let fixture = fixtures
+                .last_mut()
+                .ok_or_else(|| line.error("table header `[input]`"))?;
+            parse_answer(line, fixture)?;
+        }
+    }
+
+    Ok(fixtures)
+}
@@ -0,0 +1,116 @@
+//! Unsigned integers of any size, only addition, comparison and printing.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // little endian, without leading zero limbs
+    limbs: Vec<u32>,
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        let mut number = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        number.normalize();
+        number
+    }
+}
+
+impl BigUint {
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Number of significant bits, zero for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => 32 * (self.limbs.len() as u64 - 1) + (32 - top.leading_zeros()) as u64,
+            None => 0,
+        }
+    }
+
+    /// Divides in place by a small `divisor`, returns the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        self.normalize();
+        remainder as u32
+    }
+}
+
+impl ops::Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+
+        for idx in 0..len {
+            let a = *self.limbs.get(idx).unwrap_or(&0) as u64;
+            let b = *other.limbs.get(idx).unwrap_or(&0) as u64;
+            let sum = a + b + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+
+        BigUint { limbs }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // nine decimal digits at a time, least significant first
+        let mut number = self.clone();
+        let mut chunks = vec![];
+        while !number.is_zero() {
+            chunks.push(number.div_rem_small(1_000_000_000));
+        }
+
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        for chunk in chunks {
+            write!(f, "{chunk:09}")?;
+        }
+
+        Ok(())
+    }
+}
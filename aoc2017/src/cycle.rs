@@ -0,0 +1,74 @@
+//! Cycle detection for any `state -> state` step function.
+//!
+//! Both finders return `(mu, lambda)`: the states `x0, f(x0), f(f(x0)), ...` reach the
+//! cycle after `mu` steps and repeat every `lambda` steps from then on. The first
+//! repeated state is the one after `mu + lambda` steps.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Remembers every state with the step it was seen at, stepping `mu + lambda` times.
+pub fn hashing<T, F>(x0: T, mut f: F) -> (usize, usize)
+where
+    T: Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut state = x0;
+
+    for step in 0.. {
+        if let Some(first) = seen.get(&state) {
+            return (*first, step - first);
+        }
+        let next = f(&state);
+        seen.insert(state, step);
+        state = next;
+    }
+    unreachable!()
+}
+
+/// Brent's algorithm, in constant memory but stepping through the states about three times.
+pub fn brent<T, F>(x0: T, mut f: F) -> (usize, usize)
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // the tortoise waits at powers of two for the hare to come round
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = f(&hare);
+        lambda += 1;
+    }
+
+    // with the hare lambda steps ahead, both meet at the start of the cycle
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..lambda {
+        hare = f(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// The state after `n` steps, stepping at most `mu + lambda` times.
+pub fn nth<T, F>(x0: T, mut f: F, (mu, lambda): (usize, usize), n: usize) -> T
+where
+    F: FnMut(&T) -> T,
+{
+    let steps = if n < mu { n } else { mu + (n - mu) % lambda };
+    (0..steps).fold(x0, |state, _| f(&state))
+}
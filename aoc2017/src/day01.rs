@@ -0,0 +1,223 @@
+use std::error;
+use std::fmt;
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::windows::WindowsExt;
+use crate::{ParseError, Solution, parse};
+
+/// Digit values of the captcha, not ascii codes.
+pub type Digits = Vec<u8>;
+
+pub fn parse(input: &str) -> Result<Digits, ParseError> {
+    let line = parse::single_line(input)?;
+
+    if line.text.is_empty() {
+        return Err(line.error("sequence of digits"));
+    }
+
+    line.text
+        .char_indices()
+        .map(|(idx, ch)| match ch.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(line
+                .slice(&line.text[idx..idx + ch.len_utf8()])
+                .error("digit")),
+        })
+        .collect()
+}
+
+/// Sum of the digits that match the digit `offset` positions ahead,
+/// the sequence is circular.
+pub fn captcha(digits: &[u8], offset: usize) -> u32 {
+    let n = digits.len();
+
+    (0..n)
+        .filter(|&idx| digits[idx] == digits[(idx + offset) % n])
+        .map(|idx| digits[idx] as u32)
+        .sum()
+}
+
+/// [`captcha`] for each of `offsets`.
+pub fn captchas(digits: &[u8], offsets: &[usize]) -> Vec<u32> {
+    offsets
+        .iter()
+        .map(|&offset| captcha(digits, offset))
+        .collect()
+}
+
+/// [`captcha`] for every offset from 1 to n - 1.
+pub fn sweep(digits: &[u8]) -> Vec<(usize, u32)> {
+    (1..digits.len())
+        .map(|offset| (offset, captcha(digits, offset)))
+        .collect()
+}
+
+pub fn part1(digits: &[u8]) -> u32 {
+    // matching the next digit, the last one is followed by the first one
+    digits
+        .iter()
+        .chain(digits.first())
+        .windows::<2>()
+        .filter(|[a, b]| a == b)
+        .map(|[a, _]| *a as u32)
+        .sum()
+}
+
+pub fn part2(digits: &[u8]) -> u32 {
+    // matching the digit halfway around
+    captcha(digits, digits.len() / 2)
+}
+
+// streaming variants for inputs that don't fit in memory.
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => err.fmt(f),
+            StreamError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Reads `reader` in chunks, calls `visit` with every digit value and its index.
+/// Trailing whitespace is allowed, returns the number of digits.
+fn for_each_digit<R, F>(mut reader: R, mut visit: F) -> Result<u64, StreamError>
+where
+    R: Read,
+    F: FnMut(u64, u8),
+{
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let mut count = 0;
+    // position for errors
+    let (mut line, mut column) = (1, 1);
+    let mut trailing = false;
+
+    loop {
+        let len = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        for &byte in &chunk[..len] {
+            match byte {
+                b'0'..=b'9' if !trailing => {
+                    visit(count, byte - b'0');
+                    count += 1;
+                }
+                b'\n' => {
+                    trailing = true;
+                    line += 1;
+                    column = 0;
+                }
+                byte if byte.is_ascii_whitespace() => trailing = true,
+                byte => {
+                    let token = String::from_utf8_lossy(&[byte]).into_owned();
+                    let expected = if trailing { "end of input" } else { "digit" };
+                    let err = ParseError::new(line, column, &token, expected);
+                    return Err(StreamError::Parse(err));
+                }
+            }
+            column += 1;
+        }
+    }
+
+    if count == 0 {
+        let err = ParseError::new(line, column, "", "sequence of digits");
+        return Err(StreamError::Parse(err));
+    }
+
+    Ok(count)
+}
+
+/// [`part1`] in a single pass, keeping only the first and the previous digit.
+pub fn stream_next_digit_sum<R: Read>(reader: R) -> Result<u64, StreamError> {
+    let mut sum = 0;
+    let mut first = 0;
+    let mut previous = None;
+
+    for_each_digit(reader, |idx, digit| {
+        if idx == 0 {
+            first = digit;
+        }
+        if previous == Some(digit) {
+            sum += digit as u64;
+        }
+        previous = Some(digit);
+    })?;
+
+    // the sequence is circular
+    if previous == Some(first) {
+        sum += first as u64;
+    }
+
+    Ok(sum)
+}
+
+/// [`part2`] in two passes over a seekable reader, the first one counts digits,
+/// the second one keeps only the first half of them in memory.
+pub fn stream_half_way_sum<R: Read + Seek>(mut reader: R) -> Result<u64, StreamError> {
+    let start = reader.stream_position()?;
+    let n = for_each_digit(&mut reader, |_, _| {})?;
+    reader.seek(SeekFrom::Start(start))?;
+
+    let offset = n / 2;
+    // digits at `idx >= n - offset` wrap around to the beginning.
+    let mut first_half = Vec::with_capacity((n - offset) as usize);
+    let mut sum = 0;
+
+    for_each_digit(&mut reader, |idx, digit| {
+        if idx < n - offset {
+            first_half.push(digit);
+        }
+        // pair with the digit `offset` positions behind
+        if idx >= offset && first_half[(idx - offset) as usize] == digit {
+            sum += digit as u64;
+        }
+        // pair with the digit `offset` positions ahead, after wrapping
+        if idx >= n - offset && first_half[(idx + offset - n) as usize] == digit {
+            sum += digit as u64;
+        }
+    })?;
+
+    Ok(sum)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Inverse Captcha";
+
+    type Input = Digits;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
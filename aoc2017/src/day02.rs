@@ -0,0 +1,381 @@
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use crate::{ParseError, Solution, parse};
+
+/// Numbers a spreadsheet can hold.
+pub trait Cell:
+    Copy
+    + Ord
+    + FromStr
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Rem<Output = Self>
+{
+    const ZERO: Self;
+    const TWO: Self;
+    // for error messages
+    const NAME: &'static str;
+
+    // `None` on overflow, like the inherent methods
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! cell {
+    (@checked $t:ty) => {
+        fn checked_add(self, other: Self) -> Option<Self> {
+            <$t>::checked_add(self, other)
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+            <$t>::checked_sub(self, other)
+        }
+
+        fn checked_rem(self, other: Self) -> Option<Self> {
+            <$t>::checked_rem(self, other)
+        }
+    };
+    (unsigned $t:ty) => {
+        impl Cell for $t {
+            const ZERO: Self = 0;
+            const TWO: Self = 2;
+            const NAME: &'static str = "unsigned integer";
+
+            cell!(@checked $t);
+
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+        }
+    };
+    (signed $t:ty) => {
+        impl Cell for $t {
+            const ZERO: Self = 0;
+            const TWO: Self = 2;
+            const NAME: &'static str = "integer";
+
+            cell!(@checked $t);
+
+            fn checked_abs(self) -> Option<Self> {
+                <$t>::checked_abs(self)
+            }
+        }
+    };
+}
+
+cell!(unsigned u32);
+cell!(unsigned u64);
+cell!(signed i32);
+cell!(signed i64);
+
+pub type Spreadsheet<T> = Vec<Vec<T>>;
+
+/// Reads rows of numbers separated by whitespace, or by a single delimiter,
+/// e.g. `,` for CSV and `\t` for TSV exports.
+#[derive(Debug, Clone, Default)]
+pub struct Reader {
+    // `None` splits on any whitespace
+    delimiter: Option<String>,
+    skip_header: bool,
+}
+
+impl Reader {
+    pub fn new() -> Self {
+        Reader::default()
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter.to_string());
+        self
+    }
+
+    /// Ignores the first line, e.g. column names.
+    pub fn skip_header(mut self, skip_header: bool) -> Self {
+        self.skip_header = skip_header;
+        self
+    }
+
+    /// `row` counts from 1 without the header, like [`ChecksumError`].
+    fn read_line<T: Cell>(&self, line: parse::Token, row: usize) -> Result<Vec<T>, ParseError> {
+        let cells: Vec<parse::Token> = match &self.delimiter {
+            Some(delimiter) => line
+                .split(delimiter)
+                .map(|cell| cell.slice(cell.text.trim()))
+                .collect(),
+            None => line.split_whitespace().collect(),
+        };
+
+        if cells.iter().all(|cell| cell.text.is_empty()) {
+            return Err(line.error("row of numbers"));
+        }
+
+        cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| {
+                // blank cells are reported as missing numbers
+                let expected = format!("{} in row {row}, cell {}", T::NAME, idx + 1);
+                cell.parse(&expected)
+            })
+            .collect()
+    }
+
+    pub fn read<T: Cell>(&self, input: &str) -> Result<Spreadsheet<T>, ParseError> {
+        parse::lines(input)
+            .skip(self.skip_header as usize)
+            .enumerate()
+            .map(|(idx, line)| self.read_line(line, idx + 1))
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Spreadsheet<u32>, ParseError> {
+    Reader::new().read(input)
+}
+
+/// Why a row has no checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowError {
+    /// the row has no such value, e.g. no evenly divisible pair
+    Missing,
+    /// the value, or the sum of values up to the row, doesn't fit in a cell
+    Overflow,
+}
+
+/// A way to turn a row into a number, summed up over the rows by [`checksum`].
+pub trait RowChecksum<T: Cell> {
+    /// for error messages
+    fn name(&self) -> &str;
+
+    fn row(&self, row: &[T]) -> Result<T, RowError>;
+}
+
+/// Built-in ways to turn a row into a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checksum {
+    /// largest value minus the smallest one
+    Range,
+    /// the only two evenly divisible values divided
+    Quotient,
+    Sum,
+    /// middle value, mean of the two middle values for even rows
+    Median,
+    /// greatest common divisor of all values
+    Gcd,
+}
+
+impl Checksum {
+    pub const ALL: [Checksum; 5] = [
+        Checksum::Range,
+        Checksum::Quotient,
+        Checksum::Sum,
+        Checksum::Median,
+        Checksum::Gcd,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Checksum::Range => "range",
+            Checksum::Quotient => "quotient",
+            Checksum::Sum => "sum",
+            Checksum::Median => "median",
+            Checksum::Gcd => "gcd",
+        }
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for Checksum {
+    fn name(&self) -> &str {
+        Checksum::name(self)
+    }
+
+    fn row(&self, row: &[T]) -> Result<T, RowError> {
+        if row.is_empty() {
+            return Err(RowError::Missing);
+        }
+
+        match self {
+            Checksum::Range => {
+                let (min, max) = row
+                    .iter()
+                    .fold((row[0], row[0]), |(min, max), &x| (min.min(x), max.max(x)));
+                max.checked_sub(min).ok_or(RowError::Overflow)
+            }
+            Checksum::Quotient => quotient(row),
+            Checksum::Sum => row
+                .iter()
+                .try_fold(T::ZERO, |acc, &x| acc.checked_add(x))
+                .ok_or(RowError::Overflow),
+            Checksum::Median => {
+                let mut row = row.to_vec();
+                row.sort_unstable();
+                let mid = row.len() / 2;
+                if row.len().is_multiple_of(2) {
+                    let spread = row[mid].checked_sub(row[mid - 1]);
+                    Ok(row[mid - 1] + spread.ok_or(RowError::Overflow)? / T::TWO)
+                } else {
+                    Ok(row[mid])
+                }
+            }
+            Checksum::Gcd => row
+                .iter()
+                .fold(T::ZERO, |acc, &x| gcd(acc, x))
+                .checked_abs()
+                .ok_or(RowError::Overflow),
+        }
+    }
+}
+
+impl FromStr for Checksum {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Checksum::ALL
+            .into_iter()
+            .find(|checksum| checksum.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Checksum::ALL.iter().map(Checksum::name).collect();
+                format!(
+                    "unknown checksum `{s}`, expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+// `None` stands for the absolute value of the smallest signed integer, larger than any other.
+fn cmp_abs<T: Cell>(a: &T, b: &T) -> Ordering {
+    match (a.checked_abs(), b.checked_abs()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    }
+}
+
+// values are compared by magnitude, so `-8 4` divide to -2,
+// equal values at different positions divide to 1.
+fn quotient<T: Cell>(row: &[T]) -> Result<T, RowError> {
+    let mut row = row.to_vec();
+    row.sort_unstable_by(cmp_abs);
+
+    for (idx, &a) in row.iter().enumerate() {
+        // the only divisor as large as `a` is itself, or its negation
+        if idx > 0 && a != T::ZERO && cmp_abs(&row[idx - 1], &a).is_eq() {
+            return Ok(a / row[idx - 1]);
+        }
+
+        let half = a / T::TWO;
+        for &b in &row[..idx] {
+            // smaller divisors come first, the rest are larger than half of `a`
+            if cmp_abs(&b, &half).is_gt() {
+                break;
+            }
+            if b == T::ZERO {
+                continue;
+            }
+            match a.checked_rem(b) {
+                Some(rem) if rem == T::ZERO => return Ok(a / b),
+                Some(_) => {}
+                // the smallest signed integer by -1, its quotient doesn't fit
+                None => return Err(RowError::Overflow),
+            }
+        }
+    }
+
+    Err(RowError::Missing)
+}
+
+// signed, up to the sign of the result; the remainder only overflows
+// for the smallest signed integer by -1, which divides evenly.
+fn gcd<T: Cell>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        a
+    } else {
+        gcd(b, a.checked_rem(b).unwrap_or(T::ZERO))
+    }
+}
+
+/// Row of the spreadsheet without a checksum, counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumError {
+    pub row: usize,
+    /// name of the checksum
+    pub checksum: String,
+    pub error: RowError,
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.error {
+            RowError::Missing => {
+                write!(f, "row {}: no {} checksum", self.row, self.checksum)?;
+                if self.checksum == Checksum::Quotient.name() {
+                    write!(f, ", there is no evenly divisible pair")?;
+                }
+                Ok(())
+            }
+            RowError::Overflow => {
+                write!(f, "row {}: {} checksum overflows", self.row, self.checksum)
+            }
+        }
+    }
+}
+
+impl error::Error for ChecksumError {}
+
+/// Sum of row checksums of the whole spreadsheet.
+pub fn checksum<T, C>(table: &[Vec<T>], checksum: &C) -> Result<T, ChecksumError>
+where
+    T: Cell,
+    C: RowChecksum<T> + ?Sized,
+{
+    table
+        .iter()
+        .enumerate()
+        .try_fold(T::ZERO, |sum, (idx, row)| {
+            checksum
+                .row(row)
+                .and_then(|value| sum.checked_add(value).ok_or(RowError::Overflow))
+                .map_err(|error| ChecksumError {
+                    row: idx + 1,
+                    checksum: checksum.name().to_string(),
+                    error,
+                })
+        })
+}
+
+pub fn part1(table: &[Vec<u32>]) -> Result<u32, ChecksumError> {
+    checksum(table, &Checksum::Range)
+}
+
+pub fn part2(table: &[Vec<u32>]) -> Result<u32, ChecksumError> {
+    checksum(table, &Checksum::Quotient)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Corruption Checksum";
+
+    type Input = Spreadsheet<u32>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)?))
+    }
+}
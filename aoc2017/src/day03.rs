@@ -0,0 +1,382 @@
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::bigint::BigUint;
+use crate::{ParseError, Solution, parse};
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    let line = parse::single_line(input)?;
+
+    // squares are numbered from 1.
+    match line.parse("square number")? {
+        0 => Err(line.error("square number from 1")),
+        addr => Ok(addr),
+    }
+}
+
+/// Position of a square, `(0, 0)` is square 1, `x` grows to the right and `y` grows down.
+pub type Point = (i64, i64);
+
+// ring `k` is the border of the `(2k + 1) x (2k + 1)` square around square 1,
+// it starts right above its bottom right corner and goes counterclockwise.
+//
+//      17  16  15  14  13
+//      18   5   4   3  12
+//      19   6   1   2  11
+//      20   7   8   9  10
+//      21  22  23---> ...
+
+/// Coordinates of square `addr`, counted from 1.
+pub fn coordinates(addr: u64) -> Point {
+    assert!(addr > 0, "squares are numbered from 1");
+
+    if addr == 1 {
+        return (0, 0);
+    }
+
+    let k = u64::isqrt(addr - 1).div_ceil(2);
+    // index on the ring, each side has `2k` squares
+    let t = addr - (2 * k - 1).pow(2) - 1;
+    let (k, t) = (k as i64, t as i64);
+
+    match t / (2 * k) {
+        0 => (k, k - 1 - t),
+        1 => (k - (t - 2 * k + 1), -k),
+        2 => (-k, -k + (t - 4 * k + 1)),
+        _ => (-k + (t - 6 * k + 1), k),
+    }
+}
+
+/// Square at `point`, `None` if its number doesn't fit in `u64`.
+pub fn address((x, y): Point) -> Option<u64> {
+    let k = x.unsigned_abs().max(y.unsigned_abs()) as i128;
+
+    if k == 0 {
+        return Some(1);
+    }
+
+    let (x, y) = (x as i128, y as i128);
+    let t = if x == k && y < k {
+        k - 1 - y
+    } else if y == -k && x < k {
+        2 * k - 1 + (k - x)
+    } else if x == -k {
+        4 * k - 1 + (y + k)
+    } else {
+        6 * k - 1 + (x + k)
+    };
+
+    // `(2k - 1)^2` fits in `u128` for any `i64` coordinates
+    let first = ((2 * k - 1) as u128).pow(2) + 1;
+    u64::try_from(first + t as u128).ok()
+}
+
+/// Manhattan distance from square `addr` to square 1.
+pub fn distance(addr: u64) -> u64 {
+    let (x, y) = coordinates(addr);
+    x.unsigned_abs() + y.unsigned_abs()
+}
+
+pub fn part1(addr: u32) -> u32 {
+    distance(addr as u64) as u32
+}
+
+/// Values of the stress test spiral, additions report overflow with `None`.
+pub trait Value: Clone {
+    fn one() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! value {
+    ($($t:ty),*) => {
+        $(
+            impl Value for $t {
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+value!(u32, u64, u128);
+
+impl Value for BigUint {
+    fn one() -> Self {
+        BigUint::from(1)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Which adjacent squares are summed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// left, right, up and down
+    Four,
+    /// diagonals too, as in the puzzle
+    Eight,
+}
+
+impl Neighbourhood {
+    fn offsets(&self) -> &'static [Point] {
+        match self {
+            Neighbourhood::Four => &[(1, 0), (0, -1), (-1, 0), (0, 1)],
+            Neighbourhood::Eight => &[
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// Stress test values in spiral order as `(address, coordinates, value)`,
+/// ends when the next value overflows `T`.
+///
+/// Neighbours of a square are in its own ring or in the previous one,
+/// so only these two rings are kept.
+pub struct Spiral<T> {
+    neighbourhood: Neighbourhood,
+    addr: u64,
+    // values of squares from `first` up to `addr`
+    values: VecDeque<T>,
+    first: u64,
+    overflow: bool,
+}
+
+impl<T: Value> Spiral<T> {
+    pub fn new(neighbourhood: Neighbourhood) -> Self {
+        Spiral {
+            neighbourhood,
+            addr: 0,
+            values: VecDeque::new(),
+            first: 1,
+            overflow: false,
+        }
+    }
+}
+
+impl<T: Value> Iterator for Spiral<T> {
+    type Item = (u64, Point, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.overflow || self.addr == u64::MAX {
+            return None;
+        }
+
+        let addr = self.addr + 1;
+        let (x, y) = coordinates(addr);
+
+        let value = if addr == 1 {
+            T::one()
+        } else {
+            let mut value: Option<T> = None;
+
+            for (dx, dy) in self.neighbourhood.offsets() {
+                let neighbour = match address((x + dx, y + dy)) {
+                    // not filled yet
+                    Some(neighbour) if neighbour < addr => neighbour,
+                    _ => continue,
+                };
+                let neighbour_value = &self.values[(neighbour - self.first) as usize];
+
+                value = match value {
+                    None => Some(neighbour_value.clone()),
+                    Some(value) => match value.checked_add(neighbour_value) {
+                        Some(sum) => Some(sum),
+                        None => {
+                            self.overflow = true;
+                            return None;
+                        }
+                    },
+                };
+            }
+
+            // every square after the first one touches the previous square
+            value.unwrap()
+        };
+
+        // drop rings before the previous one
+        let k = u64::isqrt(addr - 1).div_ceil(2);
+        let keep_from = if k >= 2 { (2 * k - 3).pow(2) + 1 } else { 1 };
+        while self.first < keep_from {
+            self.values.pop_front();
+            self.first += 1;
+        }
+
+        self.values.push_back(value.clone());
+        self.addr = addr;
+
+        Some((addr, (x, y), value))
+    }
+}
+
+pub fn part2(limit: u32) -> u64 {
+    // What is the first value written that is larger than your puzzle input?
+    Spiral::<u64>::new(Neighbourhood::Eight)
+        .map(|(_, _, value)| value)
+        .find(|&value| value > limit as u64)
+        // values outgrow `u32` long before `u64` overflows
+        .unwrap()
+}
+
+// pictures of the spiral, to check it by eye.
+
+/// What is written in the squares of a rendered spiral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Address,
+    /// stress test values
+    Sum(Neighbourhood),
+}
+
+impl FromStr for Layout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "address" => Ok(Layout::Address),
+            "sum" => Ok(Layout::Sum(Neighbourhood::Eight)),
+            "sum4" => Ok(Layout::Sum(Neighbourhood::Four)),
+            _ => Err(format!(
+                "unknown layout `{s}`, expected address, sum or sum4"
+            )),
+        }
+    }
+}
+
+/// Squares of the spiral up to ring `rings`, row by row from the top left corner,
+/// as text (skipped unless `with_text`, it's slow for huge values)
+/// and as the number mapped to a colour in images.
+fn grid(rings: u32, layout: Layout, with_text: bool) -> Vec<Vec<(String, f64)>> {
+    let side = 2 * rings as usize + 1;
+    let mut grid = vec![vec![(String::new(), 0.0); side]; side];
+
+    let mut place = |(x, y): Point, square: (String, f64)| {
+        let (col, row) = (x + rings as i64, y + rings as i64);
+        grid[row as usize][col as usize] = square;
+    };
+
+    let squares = (side * side) as u64;
+    match layout {
+        Layout::Address => {
+            for addr in 1..=squares {
+                let text = if with_text {
+                    addr.to_string()
+                } else {
+                    String::new()
+                };
+                place(coordinates(addr), (text, addr as f64));
+            }
+        }
+        Layout::Sum(neighbourhood) => {
+            // values grow exponentially, colours follow their magnitude
+            for (_, point, value) in Spiral::<BigUint>::new(neighbourhood).take(squares as usize) {
+                let text = if with_text {
+                    value.to_string()
+                } else {
+                    String::new()
+                };
+                place(point, (text, value.bits() as f64));
+            }
+        }
+    }
+
+    grid
+}
+
+/// Aligned table of the squares up to ring `rings`, like the one in the puzzle.
+pub fn render_table(rings: u32, layout: Layout) -> String {
+    let grid = grid(rings, layout, true);
+    let width = grid
+        .iter()
+        .flatten()
+        .map(|(text, _)| text.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut table = String::new();
+    for row in grid {
+        let row: Vec<String> = row
+            .iter()
+            .map(|(text, _)| format!("{text:>width$}"))
+            .collect();
+        table.push_str(&row.join("  "));
+        table.push('\n');
+    }
+
+    table
+}
+
+// dark blue for the smallest values, through cyan and yellow to red for the largest.
+const PALETTE: [[f64; 3]; 5] = [
+    [0.0, 0.0, 96.0],
+    [0.0, 160.0, 255.0],
+    [96.0, 255.0, 96.0],
+    [255.0, 224.0, 0.0],
+    [224.0, 0.0, 0.0],
+];
+
+fn colour(t: f64) -> [u8; 3] {
+    let position = t.clamp(0.0, 1.0) * (PALETTE.len() - 1) as f64;
+    let idx = (position as usize).min(PALETTE.len() - 2);
+    let fraction = position - idx as f64;
+
+    let (from, to) = (PALETTE[idx], PALETTE[idx + 1]);
+    [0, 1, 2].map(|channel| (from[channel] + (to[channel] - from[channel]) * fraction) as u8)
+}
+
+/// Binary PPM image with one pixel per square up to ring `rings`.
+pub fn write_ppm<W: Write>(mut writer: W, rings: u32, layout: Layout) -> io::Result<()> {
+    let grid = grid(rings, layout, false);
+    let side = grid.len();
+
+    let colours = grid.iter().flatten().map(|(_, colour)| *colour);
+    let min = colours.clone().fold(f64::INFINITY, f64::min);
+    let max = colours.fold(f64::NEG_INFINITY, f64::max);
+    let range = if max > min { max - min } else { 1.0 };
+
+    write!(writer, "P6\n{side} {side}\n255\n")?;
+    for (_, value) in grid.iter().flatten() {
+        writer.write_all(&colour((value - min) / range))?;
+    }
+
+    writer.flush()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Spiral Memory";
+
+    type Input = u32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(*input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(*input)))
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::multiset::{LetterMultiset, Multiset};
+use crate::{ParseError, Solution};
+
+pub type Passphrase = Vec<String>;
+
+pub fn parse(input: &str) -> Result<Vec<Passphrase>, ParseError> {
+    let passphrases = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|word| word.to_string())
+                .collect()
+        })
+        .collect();
+
+    Ok(passphrases)
+}
+
+/// Characters a rule can allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lowercase,
+    Uppercase,
+    Digit,
+    Punctuation,
+}
+
+impl CharClass {
+    fn contains(&self, ch: char) -> bool {
+        match self {
+            CharClass::Lowercase => ch.is_lowercase(),
+            CharClass::Uppercase => ch.is_uppercase(),
+            CharClass::Digit => ch.is_ascii_digit(),
+            CharClass::Punctuation => ch.is_ascii_punctuation(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    UniqueWords,
+    /// no two words are made of the same letters
+    NoAnagrams,
+    MinWords(usize),
+    /// every word has at least this many characters
+    MinLength(usize),
+    AllowedChars(Vec<CharClass>),
+    /// no word is another one with its letters rotated, e.g. `abcd` and `cdab`
+    NoRotations,
+    /// no word is a part of another one
+    NoSubstrings,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::UniqueWords => write!(f, "unique words"),
+            Rule::NoAnagrams => write!(f, "no anagrams"),
+            Rule::MinWords(count) => write!(f, "at least {count} words"),
+            Rule::MinLength(len) => write!(f, "words of at least {len} characters"),
+            Rule::AllowedChars(classes) => write!(f, "only {classes:?} characters"),
+            Rule::NoRotations => write!(f, "no rotations"),
+            Rule::NoSubstrings => write!(f, "no substrings"),
+        }
+    }
+}
+
+/// Rule which rejected a passphrase, with the words that broke it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    pub words: Vec<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.rule, self.words.join(" "))
+    }
+}
+
+// equal for anagrams.
+#[derive(PartialEq, Eq, Hash)]
+enum AnagramKey {
+    Letters(LetterMultiset),
+    // bytes of different multibyte chars can add up to the same counts
+    Chars(Multiset<char>),
+}
+
+fn anagram_key(word: &str) -> AnagramKey {
+    match LetterMultiset::from_bytes(word.as_bytes()) {
+        Some(letters) => AnagramKey::Letters(letters),
+        None => AnagramKey::Chars(word.chars().collect()),
+    }
+}
+
+// lexicographically least rotation (Booth's algorithm), equal for rotations.
+fn least_rotation(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    let doubled: Vec<char> = chars.iter().chain(&chars).copied().collect();
+
+    let mut failure = vec![usize::MAX; 2 * n];
+    let mut start = 0;
+
+    for j in 1..2 * n {
+        let mut i = failure[j - start - 1];
+        while i != usize::MAX && doubled[j] != doubled[start + i + 1] {
+            if doubled[j] < doubled[start + i + 1] {
+                start = j - i - 1;
+            }
+            i = failure[i];
+        }
+        if i == usize::MAX && doubled[j] != doubled[start] {
+            if doubled[j] < doubled[start] {
+                start = j;
+            }
+            failure[j - start] = usize::MAX;
+        } else {
+            failure[j - start] = i.wrapping_add(1);
+        }
+    }
+
+    doubled[start..start + n].iter().collect()
+}
+
+// first two words with the same key.
+fn same_key<K: Eq + Hash>(passphrase: &[String], key: impl Fn(&str) -> K) -> Option<Vec<String>> {
+    let mut seen = HashMap::new();
+
+    for word in passphrase {
+        if let Some(other) = seen.insert(key(word), word) {
+            return Some(vec![other.clone(), word.clone()]);
+        }
+    }
+
+    None
+}
+
+impl Rule {
+    /// Words breaking the rule, `None` if the passphrase follows it.
+    pub fn check(&self, passphrase: &[String]) -> Option<Vec<String>> {
+        match self {
+            Rule::UniqueWords => same_key(passphrase, |word| word.to_string()),
+            Rule::NoAnagrams => same_key(passphrase, anagram_key),
+            Rule::NoRotations => same_key(passphrase, least_rotation),
+            Rule::MinWords(count) => (passphrase.len() < *count).then(|| passphrase.to_vec()),
+            Rule::MinLength(len) => {
+                let short: Vec<String> = passphrase
+                    .iter()
+                    .filter(|word| word.chars().count() < *len)
+                    .cloned()
+                    .collect();
+                (!short.is_empty()).then_some(short)
+            }
+            Rule::AllowedChars(classes) => {
+                let invalid: Vec<String> = passphrase
+                    .iter()
+                    .filter(|word| {
+                        !word
+                            .chars()
+                            .all(|ch| classes.iter().any(|class| class.contains(ch)))
+                    })
+                    .cloned()
+                    .collect();
+                (!invalid.is_empty()).then_some(invalid)
+            }
+            // there is no key for containment, each word is only compared
+            // with the words at least as long, after it in length order
+            Rule::NoSubstrings => {
+                let mut by_len: Vec<&String> = passphrase.iter().collect();
+                by_len.sort_by_key(|word| word.len());
+
+                for (idx, short) in by_len.iter().enumerate() {
+                    if let Some(long) = by_len[idx + 1..]
+                        .iter()
+                        .find(|long| long.contains(short.as_str()))
+                    {
+                        return Some(vec![short.to_string(), long.to_string()]);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Rules every passphrase must follow, checked in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Policy {
+    rules: Vec<Rule>,
+}
+
+impl Policy {
+    pub fn new() -> Self {
+        Policy::default()
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// First rule the passphrase breaks.
+    pub fn check(&self, passphrase: &[String]) -> Result<(), Violation> {
+        for rule in &self.rules {
+            if let Some(words) = rule.check(passphrase) {
+                return Err(Violation {
+                    rule: rule.clone(),
+                    words,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn count_valid(&self, passphrases: &[Passphrase]) -> usize {
+        passphrases
+            .iter()
+            .filter(|passphrase| self.check(passphrase).is_ok())
+            .count()
+    }
+}
+
+pub fn part1(pps: &[Passphrase]) -> usize {
+    Policy::new().rule(Rule::UniqueWords).count_valid(pps)
+}
+
+pub fn part2(pps: &[Passphrase]) -> usize {
+    Policy::new()
+        .rule(Rule::UniqueWords)
+        .rule(Rule::NoAnagrams)
+        .count_valid(pps)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "High-Entropy Passphrases";
+
+    type Input = Vec<Passphrase>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,288 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ParseError, Solution, parse};
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let instructions: Vec<i32> = parse::lines(input)
+        .map(|line| line.slice(line.text.trim()).parse("jump offset"))
+        .collect::<Result<_, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(parse::end_of_input(input).error("jump offset"));
+    }
+
+    Ok(instructions)
+}
+
+/// How an offset changes after its jump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutation {
+    /// offsets never change
+    Constant,
+    /// part 1
+    Increment,
+    Decrement,
+    /// decrement offsets of at least `n`, increment the others, part 2 with `n = 3`
+    DecrementFrom(i32),
+}
+
+impl Mutation {
+    pub fn apply(&self, offset: i32) -> i32 {
+        match self {
+            Mutation::Constant => offset,
+            Mutation::Increment => offset + 1,
+            Mutation::Decrement => offset - 1,
+            Mutation::DecrementFrom(n) if offset >= *n => offset - 1,
+            Mutation::DecrementFrom(_) => offset + 1,
+        }
+    }
+}
+
+impl FromStr for Mutation {
+    type Err = String;
+
+    /// `constant`, `increment`, `decrement` or `decrement-from-N`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "constant" => Ok(Mutation::Constant),
+            "increment" => Ok(Mutation::Increment),
+            "decrement" => Ok(Mutation::Decrement),
+            _ => s
+                .strip_prefix("decrement-from-")
+                .and_then(|n| n.parse().ok())
+                .map(Mutation::DecrementFrom)
+                .ok_or_else(|| format!("unknown mutation rule `{s}`")),
+        }
+    }
+}
+
+/// Why the run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// jumped past the last offset
+    Front,
+    /// jumped before the first offset
+    Back,
+    StepLimit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub exit: Exit,
+    pub steps: u64,
+    /// offsets after the run
+    pub offsets: Vec<i32>,
+    /// every visited position in order, empty unless traced
+    pub trace: Vec<usize>,
+    /// visits of every position, empty unless counted
+    pub visits: Vec<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    offsets: Vec<i32>,
+    mutation: Mutation,
+    step_limit: Option<u64>,
+    trace: bool,
+    histogram: bool,
+}
+
+impl Maze {
+    pub fn new(offsets: &[i32], mutation: Mutation) -> Self {
+        Maze {
+            offsets: offsets.to_vec(),
+            mutation,
+            step_limit: None,
+            trace: false,
+            histogram: false,
+        }
+    }
+
+    /// Stops after `steps` jumps.
+    pub fn step_limit(mut self, steps: u64) -> Self {
+        self.step_limit = Some(steps);
+        self
+    }
+
+    /// Records every visited position.
+    pub fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Counts visits of every position.
+    pub fn histogram(mut self, histogram: bool) -> Self {
+        self.histogram = histogram;
+        self
+    }
+
+    pub fn run(&self) -> Report {
+        let mut offsets = self.offsets.clone();
+        let mut trace = vec![];
+        let mut visits = if self.histogram {
+            vec![0; offsets.len()]
+        } else {
+            vec![]
+        };
+
+        let mut steps = 0;
+        let mut pc: i64 = 0;
+
+        let exit = loop {
+            if pc < 0 {
+                break Exit::Back;
+            }
+            if pc as usize >= offsets.len() {
+                break Exit::Front;
+            }
+            if self.step_limit == Some(steps) {
+                break Exit::StepLimit;
+            }
+
+            let idx = pc as usize;
+            if self.trace {
+                trace.push(idx);
+            }
+            if self.histogram {
+                visits[idx] += 1;
+            }
+
+            let jump = offsets[idx];
+            offsets[idx] = self.mutation.apply(jump);
+            pc += jump as i64;
+            steps += 1;
+        };
+
+        Report {
+            exit,
+            steps,
+            offsets,
+            trace,
+            visits,
+        }
+    }
+}
+
+pub fn part1(instructions: &[i32]) -> u64 {
+    Maze::new(instructions, Mutation::Increment).run().steps
+}
+
+// Under the part 2 rule an offset of 2 or 3 flips between the two forever, so the
+// front of the maze settles into a prefix of 2s and 3s which is only ever crossed
+// forwards. The prefix is kept as chunks of bits, set for a 3, and crossed a chunk at a
+// time through a table.
+const CHUNK: usize = 16;
+
+/// Crosses a settled chunk entered at `entry`: the flipped chunk, the number of jumps
+/// and the position it was left at, relative to the chunk start.
+fn cross_chunk(mut bits: u16, entry: usize) -> (u16, u32, usize) {
+    let mut pos = entry;
+    let mut steps = 0;
+    while pos < CHUNK {
+        let three = (bits >> pos) & 1;
+        bits ^= 1 << pos;
+        pos += 2 + three as usize;
+        steps += 1;
+    }
+    (bits, steps, pos)
+}
+
+/// [`cross_chunk`] of every chunk for the entries right after the previous chunk,
+/// indexed by `entry << 16 | bits` and packed as `bits | steps << 16 | (exit - 16) << 21`.
+fn chunk_table() -> Vec<u32> {
+    let mut table = Vec::with_capacity(3 << CHUNK);
+    for entry in 0..3 {
+        for bits in 0..=u16::MAX {
+            let (bits, steps, exit) = cross_chunk(bits, entry);
+            table.push(bits as u32 | steps << 16 | ((exit - CHUNK) as u32) << 21);
+        }
+    }
+    table
+}
+
+fn is_settled(offsets: &[i32]) -> bool {
+    offsets.iter().all(|&offset| offset == 2 || offset == 3)
+}
+
+fn pack_chunk(offsets: &[i32]) -> u16 {
+    offsets
+        .iter()
+        .rev()
+        .fold(0, |bits, &offset| bits << 1 | (offset == 3) as u16)
+}
+
+/// Same as running the maze with [`Mutation::DecrementFrom`] 3, but crosses the settled
+/// prefix in bulk.
+pub fn part2(instructions: &[i32]) -> u64 {
+    let table = chunk_table();
+    let mut offsets = instructions.to_vec();
+    // offsets before `settled` live in `chunks` only
+    let mut chunks: Vec<u16> = vec![];
+    let mut settled = 0;
+
+    let mut steps = 0;
+    let mut pc: i64 = 0;
+
+    while pc >= 0 && (pc as usize) < offsets.len() {
+        let idx = pc as usize;
+
+        if idx < settled {
+            // the first chunk may be entered anywhere, every following one right after
+            // the previous
+            let (first, entry) = (idx / CHUNK, idx % CHUNK);
+            let (bits, first_steps, exit) = cross_chunk(chunks[first], entry);
+            chunks[first] = bits;
+            steps += first_steps as u64;
+
+            let mut entry = exit - CHUNK;
+            for bits in &mut chunks[first + 1..] {
+                let crossed = table[entry << 16 | *bits as usize];
+                *bits = crossed as u16;
+                steps += ((crossed >> 16) & 0x1f) as u64;
+                entry = (crossed >> 21) as usize;
+            }
+
+            pc = (settled + entry) as i64;
+            continue;
+        }
+
+        let jump = offsets[idx];
+        offsets[idx] = if jump >= 3 { jump - 1 } else { jump + 1 };
+        pc += jump as i64;
+        steps += 1;
+
+        if idx < settled + CHUNK {
+            while settled + CHUNK <= offsets.len() && is_settled(&offsets[settled..settled + CHUNK])
+            {
+                chunks.push(pack_chunk(&offsets[settled..settled + CHUNK]));
+                settled += CHUNK;
+            }
+        }
+    }
+
+    steps
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "A Maze of Twisty Trampolines, All Alike";
+
+    type Input = Vec<i32>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
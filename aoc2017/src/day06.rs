@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ParseError, Solution, cycle, parse};
+
+pub type Banks = Vec<u32>;
+
+pub fn parse(input: &str) -> Result<Banks, ParseError> {
+    let banks: Banks = parse::lines(input)
+        .flat_map(|line| line.split_whitespace())
+        .map(|bank| bank.parse("number of blocks"))
+        .collect::<Result<_, _>>()?;
+
+    if banks.is_empty() {
+        return Err(parse::end_of_input(input).error("number of blocks"));
+    }
+
+    Ok(banks)
+}
+
+/// Which of several fullest banks is emptied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// part 1 and 2
+    First,
+    Last,
+    /// uniformly among the fullest banks, seeded for repeatable runs
+    Random(u64),
+}
+
+impl FromStr for TieBreak {
+    type Err = String;
+
+    /// `first`, `last` or `random:SEED`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first" => Ok(TieBreak::First),
+            "last" => Ok(TieBreak::Last),
+            _ => s
+                .strip_prefix("random:")
+                .and_then(|seed| seed.parse().ok())
+                .map(TieBreak::Random)
+                .ok_or_else(|| format!("unknown tie-break policy `{s}`")),
+        }
+    }
+}
+
+/// Where the blocks of the emptied bank go, one at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redistribution {
+    /// to the following banks, part 1 and 2
+    Forward,
+    /// to the preceding banks
+    Backward,
+}
+
+impl FromStr for Redistribution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "forward" => Ok(Redistribution::Forward),
+            "backward" => Ok(Redistribution::Backward),
+            _ => Err(format!("unknown redistribution policy `{s}`")),
+        }
+    }
+}
+
+impl Redistribution {
+    /// Empties bank `idx` in O(banks): every bank gets `amount / n` blocks, the emptied
+    /// one included, and the first `amount % n` banks in the direction one more.
+    pub fn apply(&self, banks: &mut Banks, idx: usize) {
+        let n = banks.len();
+        let amount = banks[idx] as usize;
+        banks[idx] = 0;
+
+        banks
+            .iter_mut()
+            .for_each(|bank| *bank += (amount / n) as u32);
+        for shift in 1..=amount % n {
+            let next = match self {
+                Redistribution::Forward => (idx + shift) % n,
+                Redistribution::Backward => (idx + n - shift) % n,
+            };
+            banks[next] += 1;
+        }
+    }
+}
+
+/// Empties the fullest bank, the first one on a tie, one block into each following bank.
+pub fn redistribute(banks: &Banks) -> Banks {
+    let mut banks = banks.clone();
+
+    // if multiple max banks have the same number of blocks,
+    // we reallocate only the first one.
+    let max = banks.iter().max().unwrap();
+    let idx_max = banks.iter().position(|bank| bank == max).unwrap();
+
+    Redistribution::Forward.apply(&mut banks, idx_max);
+    banks
+}
+
+// scrambles seeds into well mixed xorshift states
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Reallocation with any policies, remembering every reached state.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    banks: Banks,
+    tie_break: TieBreak,
+    redistribution: Redistribution,
+    rng: u64,
+    steps: usize,
+    // step every state was first reached at
+    seen: HashMap<Banks, usize>,
+}
+
+impl Simulator {
+    pub fn new(banks: &Banks) -> Self {
+        Simulator {
+            banks: banks.clone(),
+            tie_break: TieBreak::First,
+            redistribution: Redistribution::Forward,
+            rng: 0,
+            steps: 0,
+            seen: HashMap::from([(banks.clone(), 0)]),
+        }
+    }
+
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        if let TieBreak::Random(seed) = tie_break {
+            // every seed gets a stream of its own, xorshift gets stuck on zero
+            let mut state = seed;
+            self.rng = loop {
+                match splitmix64(&mut state) {
+                    0 => continue,
+                    rng => break rng,
+                }
+            };
+        }
+        self.tie_break = tie_break;
+        self
+    }
+
+    pub fn redistribution(mut self, redistribution: Redistribution) -> Self {
+        self.redistribution = redistribution;
+        self
+    }
+
+    pub fn banks(&self) -> &Banks {
+        &self.banks
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Step at which `banks` was first reached, if ever.
+    pub fn seen(&self, banks: &Banks) -> Option<usize> {
+        self.seen.get(banks).copied()
+    }
+
+    fn random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    fn fullest(&mut self) -> usize {
+        let max = *self.banks.iter().max().unwrap();
+        let mut fullest = (0..self.banks.len()).filter(|&idx| self.banks[idx] == max);
+
+        match self.tie_break {
+            TieBreak::First => fullest.next().unwrap(),
+            TieBreak::Last => fullest.next_back().unwrap(),
+            TieBreak::Random(_) => {
+                let fullest: Vec<usize> = fullest.collect();
+                fullest[(self.random() % fullest.len() as u64) as usize]
+            }
+        }
+    }
+
+    /// Reallocates once, returns the step the new state was first reached at if it's
+    /// a repeat.
+    pub fn step(&mut self) -> Option<usize> {
+        let idx = self.fullest();
+        self.redistribution.apply(&mut self.banks, idx);
+        self.steps += 1;
+
+        match self.seen.get(&self.banks) {
+            Some(&first) => Some(first),
+            None => {
+                self.seen.insert(self.banks.clone(), self.steps);
+                None
+            }
+        }
+    }
+
+    /// Reallocates until a state repeats, returns the steps taken and the steps since
+    /// the repeated state was first reached.
+    pub fn run(&mut self) -> (usize, usize) {
+        loop {
+            if let Some(first) = self.step() {
+                return (self.steps, self.steps - first);
+            }
+        }
+    }
+}
+
+pub fn part1(banks: &Banks) -> usize {
+    let (mu, lambda) = cycle::hashing(banks.clone(), redistribute);
+    mu + lambda
+}
+
+pub fn part2(banks: &Banks) -> usize {
+    let (_, lambda) = cycle::hashing(banks.clone(), redistribute);
+    lambda
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Memory Reallocation";
+
+    type Input = Banks;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,491 @@
+use std::collections::{HashMap, HashSet};
+use std::error;
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{ParseError, Solution, parse};
+
+pub type Tower = Box<Program>;
+
+pub struct Program {
+    name: String,
+    weight: u32,
+    children: Option<Vec<Tower>>,
+}
+
+#[derive(Debug)]
+pub enum TowerError {
+    Balanced,
+    /// any of these fixes balances the tower
+    Ambiguous(Vec<Fix>),
+    /// programs that need their own fixes
+    SeveralFixes(Vec<String>),
+}
+
+impl fmt::Display for TowerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TowerError::Balanced => write!(f, "the tower is already balanced"),
+            TowerError::Ambiguous(fixes) => {
+                let fixes: Vec<String> = fixes
+                    .iter()
+                    .map(|fix| format!("{} ({} -> {})", fix.name, fix.weight, fix.corrected))
+                    .collect();
+                write!(f, "ambiguous fix, either of {}", fixes.join(", "))
+            }
+            TowerError::SeveralFixes(names) => write!(
+                f,
+                "more than one program needs a new weight, unbalanced: {}",
+                names.join(", ")
+            ),
+        }
+    }
+}
+
+impl error::Error for TowerError {}
+
+type ParsedInput = HashMap<String, (u32, Vec<String>)>;
+
+pub fn parse(input: &str) -> Result<Tower, ParseError> {
+    let mut parsed_input: ParsedInput = HashMap::new();
+    // every mentioned child must be listed as a program too
+    let mut child_tokens = vec![];
+    let mut name_tokens = vec![];
+
+    for line in parse::lines(input) {
+        let mut components = line.split_whitespace();
+
+        let name = components.expect("program name")?;
+        let weight: u32 = components
+            .expect("weight")?
+            .strip("(", ")")?
+            .parse("weight")?;
+
+        let mut children = vec![];
+
+        if let Some(arrow) = components.next() {
+            arrow.expect("->")?;
+
+            let mut child = components.expect("child program name")?;
+            loop {
+                match components.next() {
+                    Some(next) => {
+                        // child names with trailing comma
+                        let child_name = child.strip("", ",")?;
+                        children.push(child_name.text.to_string());
+                        child_tokens.push(child_name);
+                        child = next;
+                    }
+                    None => {
+                        // last child name without comma
+                        children.push(child.text.to_string());
+                        child_tokens.push(child);
+                        break;
+                    }
+                }
+            }
+        }
+
+        if parsed_input
+            .insert(name.text.to_string(), (weight, children))
+            .is_some()
+        {
+            return Err(name.error("name of a program not listed yet"));
+        }
+        name_tokens.push(name);
+    }
+
+    if let Some(unknown) = child_tokens
+        .iter()
+        .find(|child| !parsed_input.contains_key(child.text))
+    {
+        return Err(unknown.error("name of a listed program"));
+    }
+
+    // a tower holds every program exactly once, so children have a single parent
+    let mut held = HashSet::new();
+    for child in &child_tokens {
+        if !held.insert(child.text) {
+            return Err(child.error("name of a program without a parent"));
+        }
+    }
+
+    let mut roots = name_tokens.iter().filter(|name| !held.contains(name.text));
+    let root = match (roots.next(), child_tokens.first()) {
+        (Some(root), _) => root,
+        // every program is held by another one, so they go round in cycles
+        (None, Some(child)) => return Err(child.error("name of a program outside a cycle")),
+        (None, None) => return Err(parse::end_of_input(input).error("program name")),
+    };
+    if let Some(second_root) = roots.next() {
+        return Err(second_root.error("name of a program held by another one"));
+    }
+
+    // the rest hang on cycles of their own, away from the root
+    let mut reached = HashSet::from([root.text]);
+    let mut programs = vec![root.text];
+    while let Some(program) = programs.pop() {
+        for child in &parsed_input[program].1 {
+            reached.insert(child.as_str());
+            programs.push(child);
+        }
+    }
+    if let Some(cycle) = child_tokens
+        .iter()
+        .find(|child| !reached.contains(child.text))
+    {
+        return Err(cycle.error("name of a program outside a cycle"));
+    }
+
+    Ok(create_tower(&parsed_input, root.text))
+}
+
+fn create_tower(parsed_input: &ParsedInput, name: &str) -> Box<Program> {
+    let program_data = parsed_input.get(name).unwrap();
+
+    let weight = program_data.0;
+
+    let mut tower = Box::new(Program {
+        name: name.to_string(),
+        weight,
+        children: None,
+    });
+
+    let children_names = &program_data.1;
+
+    let children: Vec<Tower> = children_names
+        .iter()
+        .map(|child_name| create_tower(parsed_input, child_name))
+        .collect();
+
+    if !children.is_empty() {
+        tower.children = Some(children);
+    }
+
+    tower
+}
+
+pub fn part1(root: &Tower) -> &str {
+    // What is the name of the bottom program?
+    &root.name
+}
+
+/// A new weight for one program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub name: String,
+    pub weight: u32,
+    pub corrected: u32,
+}
+
+/// A program whose children's towers don't all weigh the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unbalanced {
+    pub name: String,
+    /// every child with the total weight of its tower
+    pub towers: Vec<(String, u64)>,
+    /// the odd child out, both children when there are only two, none when changing
+    /// a single child can't balance the program
+    pub fixes: Vec<Fix>,
+}
+
+fn unbalanced(tower: &Tower, subtowers: &[Tower], totals: &[u64]) -> Option<Unbalanced> {
+    if totals.iter().all(|&total| total == totals[0]) {
+        return None;
+    }
+
+    // a child can take the total weight of any sibling, when only one sibling differs
+    // from the others it's the odd one out
+    let fixes = (0..subtowers.len())
+        .filter_map(|idx| {
+            let mut others = (0..totals.len())
+                .filter(|&other| other != idx)
+                .map(|other| totals[other]);
+            let target = others.next()?;
+            if !others.all(|total| total == target) {
+                return None;
+            }
+            // a weight that doesn't fit isn't a fix
+            let corrected = (subtowers[idx].weight as u64 + target).checked_sub(totals[idx])?;
+            let corrected = u32::try_from(corrected).ok()?;
+            Some(Fix {
+                name: subtowers[idx].name.clone(),
+                weight: subtowers[idx].weight,
+                corrected,
+            })
+        })
+        .collect();
+
+    Some(Unbalanced {
+        name: tower.name.clone(),
+        towers: subtowers
+            .iter()
+            .zip(totals)
+            .map(|(subtower, &total)| (subtower.name.clone(), total))
+            .collect(),
+        fixes,
+    })
+}
+
+/// Total weight of the tower, with every unbalanced program pushed after those above it.
+fn inspect_weights(tower: &Tower, report: &mut Vec<Unbalanced>) -> u64 {
+    match &tower.children {
+        None => tower.weight as u64,
+        Some(subtowers) => {
+            let totals: Vec<u64> = subtowers
+                .iter()
+                .map(|subtower| inspect_weights(subtower, report))
+                .collect();
+
+            report.extend(unbalanced(tower, subtowers, &totals));
+            tower.weight as u64 + totals.iter().sum::<u64>()
+        }
+    }
+}
+
+/// Every unbalanced program, top of the tower first.
+pub fn diagnose(root: &Tower) -> Vec<Unbalanced> {
+    let mut report = vec![];
+    inspect_weights(root, &mut report);
+    report
+}
+
+/// Total weight of the tower with `fix` applied, if it's balanced then.
+fn balanced_weight(tower: &Tower, fix: &Fix) -> Option<u64> {
+    let weight = if tower.name == fix.name {
+        fix.corrected
+    } else {
+        tower.weight
+    };
+
+    let totals = match &tower.children {
+        None => vec![],
+        Some(subtowers) => subtowers
+            .iter()
+            .map(|subtower| balanced_weight(subtower, fix))
+            .collect::<Option<Vec<u64>>>()?,
+    };
+
+    if totals.iter().any(|&total| total != totals[0]) {
+        return None;
+    }
+    Some(weight as u64 + totals.iter().sum::<u64>())
+}
+
+/// The single new weight that balances the whole tower.
+///
+/// Every program below the wrong one is unbalanced too, the fix is found at the top
+/// unbalanced program and checked against the whole tower, which also settles which
+/// of two children is the odd one out.
+pub fn repair(root: &Tower) -> Result<Fix, TowerError> {
+    let report = diagnose(root);
+
+    // unbalanced programs without unbalanced children, each needs a fix of its own
+    let tops: Vec<&Unbalanced> = report
+        .iter()
+        .filter(|unbalanced| {
+            unbalanced
+                .towers
+                .iter()
+                .all(|(child, _)| report.iter().all(|other| other.name != *child))
+        })
+        .collect();
+
+    let top = match tops[..] {
+        [] => return Err(TowerError::Balanced),
+        [top] => top,
+        _ => {
+            return Err(TowerError::SeveralFixes(
+                tops.iter().map(|top| top.name.clone()).collect(),
+            ));
+        }
+    };
+
+    let mut fixes: Vec<Fix> = top
+        .fixes
+        .iter()
+        .filter(|fix| balanced_weight(root, fix).is_some())
+        .cloned()
+        .collect();
+
+    match fixes.len() {
+        0 => Err(TowerError::SeveralFixes(
+            report
+                .iter()
+                .map(|unbalanced| unbalanced.name.clone())
+                .collect(),
+        )),
+        1 => Ok(fixes.remove(0)),
+        _ => Err(TowerError::Ambiguous(fixes)),
+    }
+}
+
+pub fn part2(root: &Tower) -> Result<u32, TowerError> {
+    // Given that exactly one program is the wrong weight,
+    // what would its weight need to be to balance the entire tower?
+    repair(root).map(|fix| fix.corrected)
+}
+
+/// Total weight of every program's tower.
+fn tower_weights<'t>(tower: &'t Tower, weights: &mut HashMap<&'t str, u64>) -> u64 {
+    let children = tower.children.iter().flatten();
+    let total = tower.weight as u64
+        + children
+            .map(|subtower| tower_weights(subtower, weights))
+            .sum::<u64>();
+    weights.insert(&tower.name, total);
+    total
+}
+
+fn write_tree(
+    tree: &mut String,
+    tower: &Tower,
+    weights: &HashMap<&str, u64>,
+    unbalanced: &[Unbalanced],
+    prefix: &str,
+    depth: usize,
+    max_depth: Option<usize>,
+) {
+    let subtowers = match &tower.children {
+        Some(subtowers) if max_depth.is_none_or(|max_depth| depth < max_depth) => subtowers,
+        _ => return,
+    };
+
+    for (idx, subtower) in subtowers.iter().enumerate() {
+        let last = idx + 1 == subtowers.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        tree.push_str(prefix);
+        tree.push_str(branch);
+        push_program(tree, subtower, weights, unbalanced);
+        write_tree(
+            tree,
+            subtower,
+            weights,
+            unbalanced,
+            &format!("{prefix}{indent}"),
+            depth + 1,
+            max_depth,
+        );
+    }
+}
+
+fn push_program(
+    tree: &mut String,
+    tower: &Tower,
+    weights: &HashMap<&str, u64>,
+    unbalanced: &[Unbalanced],
+) {
+    let mark = if unbalanced.iter().any(|u| u.name == tower.name) {
+        " unbalanced"
+    } else {
+        ""
+    };
+    tree.push_str(&format!(
+        "{} ({}) [{}]{mark}\n",
+        tower.name,
+        tower.weight,
+        weights[tower.name.as_str()]
+    ));
+}
+
+/// Indented text tree like `tree`'s, every program with its weight and the weight of
+/// its tower in brackets, down to `max_depth` levels above the root.
+pub fn render_tree(root: &Tower, max_depth: Option<usize>) -> String {
+    let mut weights = HashMap::new();
+    tower_weights(root, &mut weights);
+    let unbalanced = diagnose(root);
+
+    let mut tree = String::new();
+    push_program(&mut tree, root, &weights, &unbalanced);
+    write_tree(&mut tree, root, &weights, &unbalanced, "", 0, max_depth);
+    tree
+}
+
+// `text` inside a quoted DOT string, with `"` and `\` escaped.
+fn dot_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch == '"' || ch == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Graphviz DOT graph of the tower, unbalanced programs in red and their odd children
+/// out in orange with the weight they need.
+pub fn write_dot<W: Write>(mut writer: W, root: &Tower) -> io::Result<()> {
+    let mut weights = HashMap::new();
+    tower_weights(root, &mut weights);
+    let unbalanced = diagnose(root);
+    let fixes: Vec<&Fix> = unbalanced
+        .iter()
+        .filter(|u| u.fixes.len() == 1)
+        .flat_map(|u| &u.fixes)
+        .collect();
+
+    writeln!(writer, "digraph tower {{")?;
+    // the root at the bottom
+    writeln!(writer, "    rankdir=BT;")?;
+    writeln!(writer, "    node [shape=box];")?;
+
+    let mut towers = vec![root];
+    while let Some(tower) = towers.pop() {
+        let name = &tower.name;
+        let escaped = dot_escape(name);
+        // `\n` breaks lines in labels, so only the name is escaped
+        let mut label = format!(
+            "{escaped}\\nweight {}\\ntower {}",
+            tower.weight,
+            weights[name.as_str()]
+        );
+        let mut style = "";
+        if let Some(fix) = fixes.iter().find(|fix| fix.name == *name) {
+            label.push_str(&format!("\\nshould weigh {}", fix.corrected));
+            style = ", color=orange, style=bold";
+        }
+        if unbalanced.iter().any(|u| u.name == *name) {
+            style = ", color=red, style=bold";
+        }
+        writeln!(writer, "    \"{escaped}\" [label=\"{label}\"{style}];")?;
+
+        for subtower in tower.children.iter().flatten() {
+            writeln!(
+                writer,
+                "    \"{escaped}\" -> \"{}\";",
+                dot_escape(&subtower.name)
+            )?;
+        }
+        towers.extend(tower.children.iter().flatten().rev());
+    }
+
+    writeln!(writer, "}}")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Recursive Circus";
+
+    type Input = Tower;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)?))
+    }
+}
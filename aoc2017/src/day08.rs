@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Reg = String;
+
+#[derive(Debug)]
+pub enum OP {
+    Inc(Reg, i32),
+    Dec(Reg, i32),
+}
+
+impl OP {
+    fn apply<F>(&self, mut update_value: F)
+    where
+        F: FnMut(&str, i32),
+    {
+        match self {
+            OP::Inc(reg, value) => update_value(reg, *value),
+            OP::Dec(reg, value) => update_value(reg, -*value),
+        };
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum COND {
+    GT(Reg, i32),
+    GTE(Reg, i32),
+    LT(Reg, i32),
+    LTE(Reg, i32),
+    EQ(Reg, i32),
+    NEQ(Reg, i32),
+}
+
+impl COND {
+    #[rustfmt::skip]
+    fn is_true<F>(&self, get_value: F) -> bool
+    where
+        F: Fn(&str) -> i32,
+    {
+        match self {
+            COND::GT(reg, value)  => { get_value(reg) >  *value },
+            COND::GTE(reg, value) => { get_value(reg) >= *value },
+            COND::LT(reg, value)  => { get_value(reg) <  *value },
+            COND::LTE(reg, value) => { get_value(reg) <= *value },
+            COND::EQ(reg, value)  => { get_value(reg) == *value },
+            COND::NEQ(reg, value) => { get_value(reg) != *value },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    operation: OP,
+    condition: COND,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CPU {
+    registers: HashMap<String, i32>,
+    pc: usize,
+    max_register_ever: i32,
+}
+
+impl CPU {
+    fn new() -> Self {
+        CPU {
+            registers: HashMap::new(),
+            pc: 0,
+            max_register_ever: 0,
+        }
+    }
+
+    fn get_reg(&self, reg: &str) -> i32 {
+        *self.registers.get(reg).unwrap_or(&0)
+    }
+
+    fn update_reg(&mut self, reg: &str, value: i32) {
+        *self.registers.entry(reg.to_string()).or_insert(0) += value;
+    }
+
+    fn eval(&mut self, program: &[Instruction]) {
+        let mut pc = self.pc;
+
+        loop {
+            let Instruction {
+                operation: op,
+                condition: cond,
+            } = &program[pc];
+
+            let get_reg = |reg: &str| self.get_reg(reg);
+
+            if cond.is_true(get_reg) {
+                let update_reg = |reg: &str, value: i32| self.update_reg(reg, value);
+                op.apply(update_reg);
+
+                // compute highest register value for part2
+                if let Some(max) = self.max_register() {
+                    self.max_register_ever = self.max_register_ever.max(max);
+                }
+            }
+
+            pc += 1;
+            if pc == program.len() {
+                break;
+            }
+        }
+    }
+
+    fn max_register(&self) -> Option<i32> {
+        self.registers.values().max().copied()
+    }
+}
+
+/// None of the conditions ever held, so there is no largest register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoRegisterError;
+
+impl fmt::Display for NoRegisterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no register was ever written")
+    }
+}
+
+impl error::Error for NoRegisterError {}
+
+fn parse_line(line: parse::Token) -> Result<Instruction, ParseError> {
+    let mut components = line.split_whitespace();
+
+    let operation_reg = components.expect("register name")?.text.to_string();
+    let operation_name = components.expect("`inc` or `dec`")?;
+    let operation_arg = components.expect("integer")?.parse("integer")?;
+
+    #[rustfmt::skip]
+    let operation = match operation_name.text {
+        "inc"     => OP::Inc(operation_reg, operation_arg),
+        "dec"     => OP::Dec(operation_reg, operation_arg),
+        _         => return Err(operation_name.error("`inc` or `dec`")),
+    };
+
+    components.expect("`if`")?.expect("if")?;
+
+    let condition_reg = components.expect("register name")?.text.to_string();
+    let condition_name = components.expect("comparison")?;
+    let condition_arg = components.expect("integer")?.parse("integer")?;
+
+    #[rustfmt::skip]
+    let condition = match condition_name.text {
+        ">"       => COND::GT(condition_reg, condition_arg),
+        ">="      => COND::GTE(condition_reg, condition_arg),
+        "<"       => COND::LT(condition_reg, condition_arg),
+        "<="      => COND::LTE(condition_reg, condition_arg),
+        "=="      => COND::EQ(condition_reg, condition_arg),
+        "!="      => COND::NEQ(condition_reg, condition_arg),
+        _         => return Err(condition_name.error("comparison")),
+    };
+
+    components.finish()?;
+
+    Ok(Instruction {
+        operation,
+        condition,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(parse::end_of_input(input).error("instruction"));
+    }
+
+    Ok(instructions)
+}
+
+pub fn part1(program: &[Instruction]) -> Result<i32, NoRegisterError> {
+    // What is the largest value in any register
+    // after completing the instructions in your puzzle input?
+    let mut computer = CPU::new();
+    computer.eval(program);
+    computer.max_register().ok_or(NoRegisterError)
+}
+
+pub fn part2(program: &[Instruction]) -> i32 {
+    // To be safe, the CPU also needs to know
+    // the highest value held in any register during this process.
+    let mut computer = CPU::new();
+    computer.eval(program);
+    computer.max_register_ever
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "I Heard You Like Registers";
+
+    type Input = Vec<Instruction>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,130 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let line = parse::single_line(input)?;
+    let stream = line.text.as_bytes();
+    // token at `idx`, all special characters are single byte.
+    let token = |idx: usize| line.slice(&line.text[idx..idx + 1]);
+
+    let mut idx = 0;
+    let mut groups = vec![];
+    let mut garbage = None;
+
+    while idx < stream.len() {
+        match (garbage, stream[idx]) {
+            (_, b'!') if idx + 1 == stream.len() => {
+                return Err(token(idx).error("character to cancel after `!`"));
+            }
+            (_, b'!') => {
+                idx += 1;
+            }
+            (Some(..), b'>') => garbage = None,
+            (Some(..), _) => {}
+            (None, b'<') => garbage = Some(idx),
+            (None, b'{') => groups.push(idx),
+            (None, b'}') => {
+                if groups.pop().is_none() {
+                    return Err(token(idx).error("`{` before `}`"));
+                }
+            }
+            (None, _) => {}
+        }
+        idx += 1;
+    }
+
+    if let Some(start) = garbage {
+        return Err(token(start).error("garbage closed with `>`"));
+    }
+    if let Some(start) = groups.pop() {
+        return Err(token(start).error("group closed with `}`"));
+    }
+
+    Ok(line.text.to_string())
+}
+
+fn count_score_and_garbage(stream: &str) -> (u32, u32) {
+    let stream = stream.as_bytes();
+    let mut idx = 0;
+
+    let mut score = 0;
+    let mut weight = 0;
+
+    let mut garbage = 0;
+
+    loop {
+        // println!("{weight}");
+
+        if idx == stream.len() {
+            break;
+        }
+
+        match stream[idx] {
+            b'{' => {
+                weight += 1;
+                idx += 1;
+                continue;
+            }
+            b'}' => {
+                score += weight;
+                weight -= 1;
+                idx += 1;
+                continue;
+            }
+            b'!' => {
+                idx += 2;
+                continue;
+            }
+            b'<' => {
+                idx += 1;
+                while stream[idx] != b'>' {
+                    if stream[idx] == b'!' {
+                        idx += 2;
+                    } else {
+                        garbage += 1;
+                        idx += 1;
+                    }
+                }
+                idx += 1;
+                continue;
+            }
+            _ => {
+                idx += 1;
+            }
+        };
+    }
+
+    (score, garbage)
+}
+
+pub fn part1(stream: &str) -> u32 {
+    count_score_and_garbage(stream).0
+}
+
+pub fn part2(stream: &str) -> u32 {
+    count_score_and_garbage(stream).1
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Stream Processing";
+
+    type Input = String;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,62 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, knot, parse};
+
+/// Lengths for the single round of part1 and raw bytes of the input for part2.
+pub type Input = (Vec<usize>, Vec<u8>);
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let line = parse::single_line(input)?;
+
+    let lengths = line
+        .split(",")
+        .map(|l| {
+            let length = l.slice(l.text.trim()).parse("length from 0 to 256")?;
+            if length > 256 {
+                return Err(l.error("length from 0 to 256"));
+            }
+            Ok(length)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((lengths, line.text.as_bytes().to_vec()))
+}
+
+pub fn part1(input: &Input) -> u16 {
+    // one round
+    let hash = knot::sparse_hash(&input.0, 1);
+
+    hash[0] as u16 * hash[1] as u16
+}
+
+pub fn part2(input: &Input) -> String {
+    knot::knot_hash(&input.1)
+        .iter()
+        // represent as 32-char hexidecimal string
+        .map(|num| format!("{num:02x}"))
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Knot Hash";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,133 @@
+use std::error;
+use std::fmt;
+use std::ops;
+
+use crate::{ParseError, Solution, parse};
+
+// brilliant tutorial on hexagonal grids.
+// https://www.redblobgames.com/grids/hexagons/#coordinates
+
+//      \ n  /
+//    nw +--+ ne
+//      /    \
+//    -+      +-
+//      \    /
+//    sw +--+ se
+//      / s  \
+
+#[derive(Clone, Copy)]
+struct HexPoint(i32, i32);
+#[derive(Clone, Copy)]
+struct HexShift(i32, i32);
+
+impl ops::Add<HexShift> for HexPoint {
+    type Output = HexPoint;
+
+    fn add(self, rhs: HexShift) -> HexPoint {
+        HexPoint(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl HexPoint {
+    fn axial_subtract(self, b: HexPoint) -> HexPoint {
+        HexPoint(self.0 - b.0, self.1 - b.1)
+    }
+
+    fn axial_distance(self, b: HexPoint) -> u32 {
+        let vec = self.axial_subtract(b);
+        ((i32::abs(vec.0) + i32::abs(vec.0 + vec.1) + i32::abs(vec.1)) / 2) as u32
+    }
+}
+
+#[derive(Debug)]
+pub enum Dir {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl Dir {
+    #[rustfmt::skip]
+    fn from_str(direction: &str) -> Option<Self> {
+        match direction {
+            "n"  => Some(Dir::N),
+            "ne" => Some(Dir::NE),
+            "se" => Some(Dir::SE),
+            "s"  => Some(Dir::S),
+            "sw" => Some(Dir::SW),
+            "nw" => Some(Dir::NW),
+            _    => None,
+        }
+    }
+
+    #[rustfmt::skip]
+    fn get_shift(&self) -> HexShift {
+        // axial coordinates
+        // flat rotation
+        match self {
+            Dir::N  => HexShift(0, -1),
+            Dir::NE => HexShift(1, -1),
+            Dir::SE => HexShift(1, 0),
+            Dir::S  => HexShift(0, 1),
+            Dir::SW => HexShift(-1, 1),
+            Dir::NW => HexShift(-1, 0),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Dir>, ParseError> {
+    let directions = parse::single_line(input)?
+        .split(",")
+        .map(|direction| Dir::from_str(direction.text).ok_or_else(|| direction.error("direction")))
+        .collect::<Result<_, _>>()?;
+
+    Ok(directions)
+}
+
+pub fn part1(directions: &[Dir]) -> u32 {
+    // Starting where the child process started,
+    // you need to determine the fewest number of steps required to reach him.
+    let end_point = directions.iter().fold(HexPoint(0, 0), |point, direction| {
+        point + direction.get_shift()
+    });
+
+    HexPoint(0, 0).axial_distance(end_point)
+}
+
+pub fn part2(directions: &[Dir]) -> u32 {
+    // How many steps away is the furthest
+    // the child process ever got from his starting position?
+    directions
+        .iter()
+        .scan(HexPoint(0, 0), |point, direction| {
+            *point = *point + direction.get_shift();
+            Some(HexPoint(0, 0).axial_distance(*point))
+        })
+        .max()
+        .expect("expecting non-empty iterators")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Hex Ed";
+
+    type Input = Vec<Dir>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
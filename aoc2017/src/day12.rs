@@ -0,0 +1,118 @@
+use std::collections::{HashMap, VecDeque};
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Graph = HashMap<u32, Vec<u32>>;
+
+fn parse_line(line: parse::Token) -> Result<(u32, Vec<u32>), ParseError> {
+    let mut components = line.split_whitespace();
+
+    let node = components.expect("program id")?.parse("program id")?;
+    components.expect("`<->`")?.expect("<->")?;
+
+    let connected = components
+        .map(|num| {
+            let num = num.slice(num.text.strip_suffix(",").unwrap_or(num.text));
+            num.parse("program id")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if connected.is_empty() {
+        return Err(line.end().error("program id"));
+    }
+
+    Ok((node, connected))
+}
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let graph = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    Ok(graph)
+}
+
+fn bfs<F>(graph: &Graph, start: u32, mut visit: F)
+where
+    F: FnMut(u32),
+{
+    let mut visited = vec![];
+    let mut to_process = VecDeque::new();
+
+    to_process.push_back(start);
+    visited.push(start);
+
+    while let Some(node) = to_process.pop_front() {
+        visit(node);
+
+        if let Some(neighbours) = graph.get(&node) {
+            for neighbour in neighbours {
+                // is it faster than hashing u32 with HashSet?
+                match visited.binary_search(neighbour) {
+                    Ok(_) => {
+                        continue;
+                    }
+                    Err(idx) => {
+                        // inserting at `idx` keeps `visited` sorted.
+                        visited.insert(idx, *neighbour);
+                        to_process.push_back(*neighbour);
+                    }
+                };
+            }
+        }
+    }
+}
+
+pub fn part1(graph: &Graph) -> u32 {
+    // How many programs are in the group that contains program ID 0?
+    let mut group_size = 0;
+
+    bfs(graph, 0, |_| group_size += 1);
+
+    group_size
+}
+
+pub fn part2(graph: &Graph) -> u32 {
+    // How many groups are there in total?
+    let mut group_count = 0;
+
+    let mut nodes: Vec<u32> = graph.keys().copied().collect();
+    nodes.sort();
+
+    while let Some(start) = nodes.pop() {
+        group_count += 1;
+
+        bfs(graph, start, |node| {
+            if let Ok(index) = nodes.binary_search(&node) {
+                // removing from sorted vec keeps it sorted.
+                nodes.remove(index);
+            }
+        });
+    }
+
+    group_count
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Digital Plumber";
+
+    type Input = Graph;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Firewall = HashMap<u32, u32>; // depth and range
+
+fn parse_line(line: parse::Token) -> Result<(u32, u32), ParseError> {
+    let mut components = line.split(": ");
+
+    let depth = components.expect("depth")?.parse("depth")?;
+    let range = components.expect("`: ` and range")?;
+    components.finish()?;
+
+    match range.parse("range")? {
+        0 => Err(range.error("positive range")),
+        range => Ok((depth, range)),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Firewall, ParseError> {
+    let rules: Firewall = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    if rules.is_empty() {
+        return Err(parse::end_of_input(input).error("`depth: range`"));
+    }
+
+    Ok(rules)
+}
+
+pub fn part1(firewall: &Firewall) -> u32 {
+    // Given the details of the firewall you've recorded,
+    // if you leave immediately, what is the severity of your whole trip?
+    let mut severity = 0;
+    let max_layer = firewall.keys().max().unwrap();
+
+    for idx in 0..=*max_layer {
+        if let Some(depth) = firewall.get(&idx) {
+            // packet arrives
+            let time = idx;
+            // ping-pong movement.
+            // it's is rather simple to check for zero-position.
+            if time.is_multiple_of(depth * 2 - 2) {
+                severity += idx * depth;
+            }
+        }
+    }
+
+    severity
+}
+
+pub fn part2(firewall: &Firewall) -> u32 {
+    // What is the fewest number of picoseconds that you need to delay the packet
+    // to pass through the firewall without being caught?
+    let mut delay = 0;
+    let max_layer = firewall.keys().max().unwrap();
+
+    loop {
+        let mut found = true;
+        for idx in 0..=*max_layer {
+            if let Some(depth) = firewall.get(&idx) {
+                let delayed = idx + delay;
+                if delayed.is_multiple_of(depth * 2 - 2) {
+                    found = false;
+                    break;
+                }
+            }
+        }
+        if found {
+            break delay;
+        }
+        delay += 1;
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Packet Scanners";
+
+    type Input = Firewall;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, knot, parse};
+
+#[derive(Debug)]
+struct BitField128 {
+    arr: Vec<u8>,
+}
+
+impl BitField128 {
+    fn with_vec(numbers: Vec<u8>) -> Self {
+        assert!(numbers.len() == 2048);
+
+        BitField128 { arr: numbers }
+    }
+
+    fn is_used(&self, col: usize, row: usize) -> bool {
+        let idx = row * 128 + col;
+        let n_idx = idx / 8;
+        let shift = 7 - idx % 8;
+
+        self.arr[n_idx] & (1 << shift) != 0
+    }
+
+    fn set_unused(&mut self, col: usize, row: usize) {
+        let idx = row * 128 + col;
+        let n_idx = idx / 8;
+        let shift = 7 - idx % 8;
+
+        self.arr[n_idx] &= !(1 << shift);
+    }
+}
+
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let key = parse::single_line(input)?;
+
+    if key.text.is_empty() {
+        return Err(key.error("key string"));
+    }
+
+    Ok(key.text.to_string())
+}
+
+fn calculate_dense_hash(input: &str, idx: usize) -> Vec<u8> {
+    let mut to_hash = input.to_string();
+    to_hash.push('-');
+    to_hash.push_str(&idx.to_string());
+
+    knot::knot_hash(to_hash.as_bytes())
+}
+
+pub fn part1(input: &str) -> u32 {
+    // Given your actual key string, how many squares are used?
+    (0..128)
+        .map(|idx| {
+            calculate_dense_hash(input, idx)
+                .iter()
+                .map(|num| num.count_ones())
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+fn region_mark(disk: &mut BitField128, col: usize, row: usize) {
+    let mut to_process = VecDeque::new();
+
+    to_process.push_back((col, row));
+
+    while let Some((col, row)) = to_process.pop_front() {
+        disk.set_unused(col, row);
+
+        for (dr, dc) in [(0, 1), (1, 0), (0, !0), (!0, 0)] {
+            // https://t.me/bminaiev_blog/59
+            let nrow = row.overflowing_add(dr).0;
+            let ncol = col.overflowing_add(dc).0;
+
+            if nrow < 128 && ncol < 128 && disk.is_used(ncol, nrow) {
+                to_process.push_back((ncol, nrow));
+            }
+        }
+    }
+}
+
+pub fn part2(input: &str) -> u32 {
+    // How many regions are present given your key string?
+
+    let mut disk = BitField128::with_vec(
+        (0..128)
+            .flat_map(|idx| calculate_dense_hash(input, idx))
+            .collect(),
+    );
+
+    let mut regions_count = 0;
+
+    for row in 0..128 {
+        for col in 0..128 {
+            if disk.is_used(col, row) {
+                regions_count += 1;
+                region_mark(&mut disk, col, row);
+            }
+        }
+    }
+
+    regions_count
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Disk Defragmentation";
+
+    type Input = String;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
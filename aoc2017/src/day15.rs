@@ -0,0 +1,119 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+struct Generator<F: Fn(u64) -> bool> {
+    current_value: u64,
+    factor: u64,
+    divider: u64,
+    terminator: F,
+}
+
+impl<F: Fn(u64) -> bool> Iterator for Generator<F> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current_value = (self.current_value) * self.factor % self.divider;
+
+        while !(self.terminator)(self.current_value) {
+            self.current_value = (self.current_value) * self.factor % self.divider;
+        }
+
+        Some(self.current_value)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let mut init_values = vec![];
+
+    for name in ["A", "B"] {
+        let line = lines.next().unwrap_or_else(|| parse::end_of_input(input));
+        let mut components = line.split_whitespace();
+
+        components.expect("`Generator`")?.expect("Generator")?;
+        components.expect("generator name")?.expect(name)?;
+        components.expect("`starts`")?.expect("starts")?;
+        components.expect("`with`")?.expect("with")?;
+
+        let value = components.expect("starting value")?;
+        match value.parse("starting value")? {
+            init_value if init_value < 2147483647 => init_values.push(init_value),
+            _ => return Err(value.error("starting value below 2147483647")),
+        }
+
+        components.finish()?;
+    }
+
+    if let Some(extra) = lines.find(|line| !line.text.trim().is_empty()) {
+        return Err(extra.error("end of input"));
+    }
+
+    Ok(init_values)
+}
+
+pub fn part1(init_values: &[u64]) -> usize {
+    let generator_a = Generator {
+        current_value: init_values[0],
+        factor: 16807,
+        divider: 2147483647,
+        terminator: |_| true,
+    };
+    let generator_b = Generator {
+        current_value: init_values[1],
+        factor: 48271,
+        divider: 2147483647,
+        terminator: |_| true,
+    };
+
+    generator_a
+        .zip(generator_b)
+        .take(40_000_000)
+        .map(|(a, b)| ((a ^ b) & 0xFFFF == 0) as usize)
+        .sum()
+}
+
+pub fn part2(init_values: &[u64]) -> usize {
+    let generator_a = Generator {
+        current_value: init_values[0],
+        factor: 16807,
+        divider: 2147483647,
+        terminator: |x| x.is_multiple_of(4),
+    };
+    let generator_b = Generator {
+        current_value: init_values[1],
+        factor: 48271,
+        divider: 2147483647,
+        terminator: |x| x.is_multiple_of(8),
+    };
+
+    generator_a
+        .zip(generator_b)
+        .take(5_000_000)
+        .map(|(a, b)| ((a ^ b) & 0xFFFF == 0) as usize)
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Dueling Generators";
+
+    type Input = Vec<u64>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, cycle, parse};
+
+pub type Moves = Vec<Move>;
+
+pub enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(u8, u8),
+}
+
+impl Move {
+    fn from_token(token: parse::Token) -> Result<Self, ParseError> {
+        let position = |token: parse::Token| match token.parse("position")? {
+            position if position < 16 => Ok(position),
+            _ => Err(token.error("position from 0 to 15")),
+        };
+        let program = |token: parse::Token| match token.text.as_bytes() {
+            [name @ b'a'..=b'p'] => Ok(*name),
+            _ => Err(token.error("program name from `a` to `p`")),
+        };
+
+        let args = token.slice(token.text.get(1..).unwrap_or_default());
+
+        match token.text.as_bytes().first() {
+            Some(b's') => match args.parse("spin size")? {
+                size if size <= 16 => Ok(Move::Spin(size)),
+                _ => Err(args.error("spin size from 0 to 16")),
+            },
+            Some(b'x') => {
+                let mut programs = args.split("/");
+                let a = position(programs.expect("position")?)?;
+                let b = position(programs.expect("`/` and position")?)?;
+                programs.finish()?;
+
+                Ok(Move::Exchange(a, b))
+            }
+            Some(b'p') => {
+                let mut programs = args.split("/");
+                let a = program(programs.expect("program name")?)?;
+                let b = program(programs.expect("`/` and program name")?)?;
+                programs.finish()?;
+
+                Ok(Move::Partner(a, b))
+            }
+            _ => Err(token.error("move: spin, exchange or partner")),
+        }
+    }
+
+    fn apply_mut(&self, state: &mut VecDeque<u8>) {
+        match self {
+            Move::Spin(x) => {
+                state.rotate_right(*x);
+            }
+            Move::Exchange(a, b) => {
+                state.swap(*a, *b);
+            }
+            Move::Partner(a, b) => {
+                let mut idx_a = 0;
+                let mut idx_b = 0;
+                let mut count = 0;
+
+                for (idx, x) in state.iter().enumerate() {
+                    if x == a {
+                        idx_a = idx;
+                        count += 1;
+                    }
+                    if x == b {
+                        idx_b = idx;
+                        count += 1;
+                    }
+                    if count == 2 {
+                        break;
+                    }
+                }
+
+                state.swap(idx_a, idx_b);
+            }
+        };
+    }
+}
+
+pub fn parse(input: &str) -> Result<Moves, ParseError> {
+    let moves = parse::single_line(input)?
+        .split(",")
+        .map(Move::from_token)
+        .collect::<Result<_, _>>()?;
+
+    Ok(moves)
+}
+
+pub fn part1(moves: &[Move]) -> String {
+    let mut state: VecDeque<u8> = (b'a'..=b'p').collect();
+
+    moves.iter().for_each(|m| {
+        m.apply_mut(&mut state);
+    });
+
+    state.iter().map(|c| *c as char).collect()
+}
+
+pub fn part2(moves: &[Move]) -> String {
+    let state: VecDeque<u8> = (b'a'..=b'p').collect();
+
+    // the dance only rearranges the programs, so it repeats itself
+    // long before 1 billion rounds.
+    let dance = |state: &VecDeque<u8>| {
+        let mut state = state.clone();
+        moves.iter().for_each(|m| {
+            m.apply_mut(&mut state);
+        });
+        state
+    };
+    let cycle = cycle::brent(state.clone(), dance);
+    let state = cycle::nth(state, dance, cycle, 1_000_000_000);
+
+    state.iter().map(|c| *c as char).collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Permutation Promenade";
+
+    type Input = Moves;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
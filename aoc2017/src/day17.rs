@@ -0,0 +1,58 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub fn parse(input: &str) -> Result<usize, ParseError> {
+    parse::single_line(input)?.parse("number of steps")
+}
+
+pub fn part1(steps: usize) -> u32 {
+    let mut buf = vec![0];
+    let mut idx = 0;
+
+    (1..=2017).for_each(|el| {
+        idx = (idx + steps) % buf.len() + 1;
+        buf.insert(idx, el);
+    });
+
+    buf[idx + 1]
+}
+
+pub fn part2(steps: usize) -> u32 {
+    let mut result = 0;
+    let mut idx = 0;
+    let mut buf_len = 1;
+
+    (1..=50_000_000).for_each(|el| {
+        idx = (idx + steps) % buf_len + 1;
+        if idx == 1 {
+            result = el;
+        }
+        buf_len += 1;
+    });
+
+    result
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Spinlock";
+
+    type Input = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(*input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(*input)))
+    }
+}
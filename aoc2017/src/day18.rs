@@ -0,0 +1,304 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+#[derive(Debug)]
+pub enum Operand {
+    Register(char),
+    Value(isize),
+}
+
+impl Operand {
+    fn from_token(token: parse::Token) -> Result<Self, ParseError> {
+        match (token.text.parse::<isize>(), token.text.as_bytes()) {
+            (Ok(integer), _) => Ok(Operand::Value(integer)),
+            (Err(..), [name @ b'a'..=b'z']) => Ok(Operand::Register(*name as char)),
+            _ => Err(token.error("register name or integer")),
+        }
+    }
+
+    // operands which are written to
+    fn register_from_token(token: parse::Token) -> Result<Self, ParseError> {
+        match token.text.as_bytes() {
+            [name @ b'a'..=b'z'] => Ok(Operand::Register(*name as char)),
+            _ => Err(token.error("register name")),
+        }
+    }
+
+    fn get_value(&self, registers: &mut Registers) -> isize {
+        match self {
+            Operand::Register(op_name) => *registers.entry(*op_name).or_insert(0),
+            Operand::Value(op_value) => *op_value,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Snd(Operand),
+    Set(Operand, Operand),
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+    Mod(Operand, Operand),
+    Rcv(Operand),
+    Jgz(Operand, Operand),
+}
+
+impl Instruction {
+    fn from_token(line: parse::Token) -> Result<Self, ParseError> {
+        let mut components = line.split_whitespace();
+        let name = components.expect("instruction")?;
+
+        let instruction = match name.text {
+            // one operand
+            "snd" => Instruction::Snd(Operand::from_token(components.expect("operand")?)?),
+            // received value is stored into the register
+            "rcv" => Instruction::Rcv(Operand::register_from_token(
+                components.expect("register name")?,
+            )?),
+            // two operands
+            "set" => Instruction::Set(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "add" => Instruction::Add(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "mul" => Instruction::Mul(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "mod" => Instruction::Mod(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "jgz" => Instruction::Jgz(
+                Operand::from_token(components.expect("operand")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            _ => return Err(name.error("instruction")),
+        };
+
+        components.finish()?;
+
+        Ok(instruction)
+    }
+
+    fn apply_mut<F>(&self, cpu: &mut CPU<F>)
+    where
+        F: FnMut(&Instruction, &mut Registers) -> bool,
+    {
+        match self {
+            Instruction::Set(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) =
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Add(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) +=
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Mul(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) *=
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Mod(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) %=
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Snd(..) => {
+                // processed via trap
+                cpu.pc += 1;
+            }
+            Instruction::Rcv(..) => {
+                // processed via trap
+                cpu.pc += 1;
+            }
+            Instruction::Jgz(operand1, operand2) => {
+                let is_jmp = operand1.get_value(&mut cpu.registers) > 0;
+                let offset = operand2.get_value(&mut cpu.registers);
+
+                if is_jmp {
+                    cpu.pc += offset;
+                } else {
+                    cpu.pc += 1;
+                }
+            }
+            instruction => unimplemented!("unknown instruction: {:?}", instruction),
+        }
+    }
+}
+
+type Registers = HashMap<char, isize>;
+
+#[allow(clippy::upper_case_acronyms)]
+struct CPU<F>
+where
+    F: FnMut(&Instruction, &mut Registers) -> bool,
+{
+    registers: Registers,
+    pc: isize,
+    trap: F,
+    is_waiting: bool,
+}
+
+impl<F: FnMut(&Instruction, &mut Registers) -> bool> CPU<F> {
+    fn new(trap: F) -> Self {
+        CPU {
+            registers: HashMap::new(),
+            pc: 0,
+            trap,
+            is_waiting: false,
+        }
+    }
+
+    fn eval(&mut self, instructions: &[Instruction]) {
+        loop {
+            let next_instruction = &instructions[self.pc as usize];
+
+            // println!("{}", self.pc);
+            // println!("{:?}", next_instruction);
+            // std::thread::sleep(std::time::Duration::from_millis(1000));
+
+            if (self.trap)(next_instruction, &mut self.registers) {
+                self.is_waiting = true;
+                break;
+            };
+
+            self.is_waiting = false;
+
+            next_instruction.apply_mut(self);
+
+            if self.pc as usize >= instructions.len() {
+                break;
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = parse::lines(input)
+        .map(Instruction::from_token)
+        .collect::<Result<_, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(parse::end_of_input(input).error("instruction"));
+    }
+
+    Ok(instructions)
+}
+
+pub fn part1(instructions: &[Instruction]) -> isize {
+    // What is the value of the recovered frequency
+    // (the value of the most recently played sound)
+    // the first time a rcv instruction is executed with a non-zero value?
+    let mut cpu = CPU::new(|instruction, registers| {
+        match instruction {
+            Instruction::Snd(operand) => {
+                // save last played frequency into `~` register
+                *registers.entry('~').or_insert(0) = operand.get_value(registers);
+            }
+            Instruction::Rcv(operand) if operand.get_value(registers) != 0 => {
+                return true;
+            }
+            _ => {}
+        };
+        false
+    });
+
+    cpu.eval(instructions);
+
+    *cpu.registers.get(&'~').unwrap()
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    // Once both of your programs have terminated
+    // (regardless of what caused them to do so),
+    // how many times did program 1 send a value?
+    let queue0 = RefCell::new(VecDeque::new());
+    let queue1 = RefCell::new(VecDeque::new());
+
+    let mut cpu0 = CPU::new(|instruction, registers| {
+        match instruction {
+            Instruction::Snd(operand) => {
+                queue1.borrow_mut().push_back(operand.get_value(registers));
+                // use `~` register as a counter
+                *registers.entry('~').or_insert(0) += 1;
+            }
+            Instruction::Rcv(Operand::Register(name)) => {
+                if let Some(value) = queue0.borrow_mut().pop_front() {
+                    *registers.entry(*name).or_insert(0) = value;
+                } else {
+                    return true;
+                }
+            }
+            _ => {}
+        };
+        false
+    });
+    cpu0.registers.entry('p').or_insert(0);
+
+    let mut cpu1 = CPU::new(|instruction, registers| {
+        match instruction {
+            Instruction::Snd(operand) => {
+                queue0.borrow_mut().push_back(operand.get_value(registers));
+                // use `~` register as a counter
+                *registers.entry('~').or_insert(0) += 1;
+            }
+            Instruction::Rcv(Operand::Register(name)) => {
+                if let Some(value) = queue1.borrow_mut().pop_front() {
+                    *registers.entry(*name).or_insert(0) = value;
+                } else {
+                    return true;
+                }
+            }
+            _ => {}
+        };
+        false
+    });
+    cpu1.registers.entry('p').or_insert(1);
+
+    loop {
+        cpu0.eval(instructions);
+        cpu1.eval(instructions);
+
+        if cpu0.is_waiting
+            && queue0.borrow().is_empty()
+            && cpu1.is_waiting
+            && queue1.borrow().is_empty()
+        {
+            break;
+        }
+    }
+
+    *cpu1.registers.get(&'~').unwrap() as usize
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Duet";
+
+    type Input = Vec<Instruction>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
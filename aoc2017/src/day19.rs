@@ -0,0 +1,165 @@
+use std::error;
+use std::fmt;
+use std::iter;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Map = Vec<u8>;
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let mut lines = parse::lines(input);
+    let first = lines
+        .next()
+        .ok_or_else(|| parse::end_of_input(input).error("map"))?;
+
+    if !first.text.contains('|') {
+        return Err(first.error("start of the path `|` on the first line"));
+    }
+
+    let width = first.text.len();
+    let mut map = Map::new();
+
+    // the walker relies on every line being of the same length, ending with `\n`.
+    for line in iter::once(first).chain(lines) {
+        if let Some((idx, ch)) = line
+            .text
+            .char_indices()
+            .find(|&(_, ch)| !matches!(ch, ' ' | '|' | '-' | '+' | 'A'..='Z'))
+        {
+            let token = line.slice(&line.text[idx..idx + ch.len_utf8()]);
+            return Err(token.error("one of ` |-+` or a letter"));
+        }
+        if line.text.len() != width {
+            return Err(line.error(format!("line of {width} characters")));
+        }
+
+        map.extend(line.text.bytes());
+        map.push(b'\n');
+    }
+
+    Ok(map)
+}
+
+fn walker<F>(map: &Map, mut visit: F)
+where
+    F: FnMut(u8),
+{
+    let line_count = map.iter().fold(0, |acc, el| acc + (*el == 10) as usize);
+    let line_length = map.len() / line_count;
+
+    let mut idx = 0;
+    let starting_point = loop {
+        if map[idx] == b'|' {
+            break idx;
+        }
+        idx += 1;
+    };
+
+    // starting from first `|`, going down.
+    let mut current_point = starting_point as isize;
+    let mut dx: isize = 0;
+    let mut dy: isize = 1;
+
+    'walk: loop {
+        // walking till `+` or stop walking if reached ` `
+        while map[current_point as usize] != b'+' {
+            visit(map[current_point as usize]);
+
+            if map[current_point as usize] == b' ' {
+                break 'walk;
+            }
+            current_point += dy * line_length as isize;
+        }
+
+        // standing on `+`
+        visit(map[current_point as usize]);
+
+        // change direction, left or right
+        dy = 0;
+        if current_point > 0 && map[current_point as usize - 1] != b' ' {
+            dx = -1;
+        }
+        if current_point as usize + 1 < map.len() && map[current_point as usize + 1] != b' ' {
+            dx = 1;
+        }
+        // step in new direction
+        current_point += dx;
+
+        // walking till `+` or stop walking if reached ` `
+        while map[current_point as usize] != b'+' {
+            visit(map[current_point as usize]);
+
+            if map[current_point as usize] == b' ' {
+                break 'walk;
+            }
+            current_point += dx;
+        }
+        // standing on `+`
+        visit(map[current_point as usize]);
+
+        // change direction, up or down
+        dx = 0;
+        if current_point - line_length as isize >= 0
+            && map[current_point as usize - line_length] != b' '
+        {
+            dy = -1;
+        }
+        if current_point as usize + line_length < map.len()
+            && map[current_point as usize + line_length] != b' '
+        {
+            dy = 1;
+        }
+        // step in new direction
+        current_point += dy * line_length as isize;
+
+        // repeat
+    }
+}
+
+pub fn part1(map: &Map) -> String {
+    // What letters will it see (in the order it would see them)
+    // if it follows the path?
+    let mut letters = String::new();
+
+    walker(map, |cell| {
+        if cell != b'|' && cell != b'-' && cell != b'+' && cell != b' ' {
+            letters.push(cell as char);
+        }
+    });
+
+    letters
+}
+
+pub fn part2(map: &Map) -> usize {
+    // How many steps does the packet need to go?
+    let mut steps = 0;
+
+    walker(map, |_| {
+        steps += 1;
+    });
+    
+    // don't count standing at start as a step.
+    steps - 1
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "A Series of Tubes";
+
+    type Input = Map;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,208 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+#[rustfmt::skip]
+#[derive(Debug, Clone)]
+pub struct Particle {
+    position:       [i64; 3],
+    velocity:       [i64; 3],
+    acceleration:   [i64; 3],
+}
+
+impl Particle {
+    fn update(&mut self) {
+        self.velocity[0] += self.acceleration[0];
+        self.position[0] += self.velocity[0];
+        self.velocity[1] += self.acceleration[1];
+        self.position[1] += self.velocity[1];
+        self.velocity[2] += self.acceleration[2];
+        self.position[2] += self.velocity[2];
+    }
+}
+
+impl Particle {
+    fn from_token(token: parse::Token) -> Result<Self, ParseError> {
+        // the puzzle description pads coordinates with spaces, e.g. `p=< 3,0,0>`.
+        let coordinate = |token: parse::Token| token.slice(token.text.trim()).parse("integer");
+        let vector = |token: parse::Token, name: &str| -> Result<[i64; 3], ParseError> {
+            let mut coordinates = token.strip(&format!("{name}=<"), ">")?.split(",");
+            let vector = [
+                coordinate(coordinates.expect("x coordinate")?)?,
+                coordinate(coordinates.expect("`,` and y coordinate")?)?,
+                coordinate(coordinates.expect("`,` and z coordinate")?)?,
+            ];
+            coordinates.finish()?;
+
+            Ok(vector)
+        };
+
+        let mut components = token.split(", ");
+        let position = vector(components.expect("position `p=<x,y,z>`")?, "p")?;
+        let velocity = vector(components.expect("`, ` and velocity `v=<x,y,z>`")?, "v")?;
+        let acceleration = vector(components.expect("`, ` and acceleration `a=<x,y,z>`")?, "a")?;
+        components.finish()?;
+
+        Ok(Particle {
+            position,
+            velocity,
+            acceleration,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Particle>, ParseError> {
+    let particles: Vec<Particle> = parse::lines(input)
+        .map(Particle::from_token)
+        .collect::<Result<_, _>>()?;
+
+    if particles.is_empty() {
+        return Err(parse::end_of_input(input).error("particle"));
+    }
+
+    Ok(particles)
+}
+
+pub fn part1(particles: &[Particle]) -> usize {
+    // Which particle will stay closest to position <0,0,0> in the long term?
+
+    // first we need to find particles with the lowest acceleration,
+    // because in the long term these particles will scatter less.
+    // if the lowest accelerations are equal for some group of particles,
+    // we must look for particles with the lowest velocity within this group.
+    // if after that we have group of particles moving equally slow,
+    // or not moving at all,
+    // closest to <0, 0, 0> will be the one which is already closest.
+
+    // sort by acceleration, velocity and position in this particular order,
+    // get the minimum.
+
+    particles
+        .iter()
+        .enumerate()
+        .map(|(idx, particle)| {
+            (
+                particle
+                    .acceleration
+                    .iter()
+                    .copied()
+                    .fold(0, |acc, c| acc + i64::abs(c)),
+                particle
+                    .velocity
+                    .iter()
+                    .copied()
+                    .fold(0, |acc, c| acc + i64::abs(c)),
+                particle
+                    .position
+                    .iter()
+                    .copied()
+                    .fold(0, |acc, c| acc + i64::abs(c)),
+                idx,
+            )
+        })
+        // compares tuples lexicographically.
+        .min()
+        .unwrap()
+        .3
+}
+
+fn filter_out_collided(particles: &mut Vec<Particle>) -> usize {
+    let mut removed_count = 0;
+
+    particles.sort_by_key(|el| el.position);
+
+    let mut c = 1;
+    let mut removing = false;
+
+    loop {
+        if c == particles.len() {
+            break;
+        }
+        if particles[c - 1].position == particles[c].position {
+            removing = true;
+            particles.remove(c - 1);
+            removed_count += 1;
+        } else if removing {
+            particles.remove(c - 1);
+            removed_count += 1;
+            removing = false;
+        } else {
+            c += 1;
+        }
+    }
+
+    removed_count
+}
+
+fn calculate_pairs_distances(particles: &[Particle]) -> Vec<i64> {
+    let mut distances = vec![];
+
+    for (idx1, p1) in particles.iter().enumerate() {
+        for (idx2, p2) in particles.iter().enumerate() {
+            if idx1 != idx2 {
+                distances.push(
+                    i64::abs(p1.position[0] - p2.position[0])
+                        + i64::abs(p1.position[1] - p2.position[1])
+                        + i64::abs(p1.position[2] - p2.position[2]),
+                );
+            }
+        }
+    }
+
+    distances
+}
+
+pub fn part2(particles: &[Particle]) -> usize {
+    // How many particles are left after all collisions are resolved?
+
+    let mut particles = particles.to_owned();
+
+    loop {
+        let distances = calculate_pairs_distances(&particles);
+
+        particles.iter_mut().for_each(|p| {
+            p.update();
+        });
+
+        let removed_count = filter_out_collided(&mut particles);
+
+        if removed_count == 0 {
+            let next_distances = calculate_pairs_distances(&particles);
+            if distances
+                .iter()
+                .zip(next_distances.iter())
+                .map(|(a, b)| a - b)
+                .all(|d| d < 0)
+            {
+                // all collisions are resolved when particles
+                // are scattering away from each other.
+                break;
+            }
+        }
+    }
+
+    particles.len()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Particle Swarm";
+
+    type Input = Vec<Particle>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
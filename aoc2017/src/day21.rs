@@ -0,0 +1,314 @@
+use std::error;
+use std::fmt;
+use std::mem;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Rule = (Vec<Vec<u8>>, Vec<u8>);
+
+// pattern of `size` rows separated by `/`, kept as is.
+fn parse_pattern(token: parse::Token, sizes: &[usize]) -> Result<Vec<u8>, ParseError> {
+    let expected = || {
+        let sizes: Vec<String> = sizes.iter().map(|n| format!("{n}x{n}")).collect();
+        format!("{} pattern of `.` and `#`", sizes.join(" or "))
+    };
+
+    let rows: Vec<&str> = token.text.split('/').collect();
+    let size = rows.len();
+
+    if !sizes.contains(&size)
+        || rows.iter().any(|row| row.len() != size)
+        || rows
+            .iter()
+            .any(|row| row.bytes().any(|b| b != b'.' && b != b'#'))
+    {
+        return Err(token.error(expected()));
+    }
+
+    Ok(token.text.as_bytes().to_owned())
+}
+
+fn parse_line(line: parse::Token) -> Result<Rule, ParseError> {
+    let mut patterns = line.split(" => ");
+    let input = patterns.expect("input pattern")?;
+    let output = patterns.expect("` => ` and output pattern")?;
+    patterns.finish()?;
+
+    // enhancement always grows the square by one.
+    let size = input.text.split('/').count();
+    let input = parse_pattern(input, &[2, 3])?;
+    let output = parse_pattern(output, &[size + 1])?;
+
+    Ok((vec![input], output))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rule>, ParseError> {
+    let rules: Vec<Rule> = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    if rules.is_empty() {
+        return Err(parse::end_of_input(input).error("`pattern => pattern`"));
+    }
+
+    Ok(rules)
+}
+
+// fn print_image(image: &[u8]) {
+//     image.iter().for_each(|pixel| match pixel {
+//         b'/' => {
+//             print!("\n");
+//         }
+//         sym => {
+//             print!("{}", *sym as char);
+//         }
+//     });
+//     print!("\n");
+// }
+
+fn flip_vertically(image: &mut [u8], n: usize) {
+    for i in 0..n / 2 {
+        for j in 0..n {
+            let idx_a = i * (n + 1) + j;
+            let idx_b = (n + 1) * (n - i - 1) + j;
+            mem::swap(
+                unsafe { image.as_mut_ptr().add(idx_a).as_mut().unwrap() },
+                unsafe { image.as_mut_ptr().add(idx_b).as_mut().unwrap() },
+            );
+        }
+    }
+}
+
+fn flip_horizontally(image: &mut [u8], n: usize) {
+    for i in 0..n {
+        for j in 0..n / 2 {
+            let idx_a = i * (n + 1) + j;
+            let idx_b = i * (n + 1) + (n - j - 1);
+            mem::swap(
+                unsafe { image.as_mut_ptr().add(idx_a).as_mut().unwrap() },
+                unsafe { image.as_mut_ptr().add(idx_b).as_mut().unwrap() },
+            );
+        }
+    }
+}
+
+fn transpose(image: &mut [u8], n: usize) {
+    for i in 0..n {
+        for j in i..n {
+            let idx_a = i * (n + 1) + j;
+            let idx_b = j * (n + 1) + i;
+            mem::swap(
+                unsafe { image.as_mut_ptr().add(idx_a).as_mut().unwrap() },
+                unsafe { image.as_mut_ptr().add(idx_b).as_mut().unwrap() },
+            );
+        }
+    }
+}
+
+fn rotate_ccw(image: &mut [u8], n: usize, count: usize) {
+    for _ in 0..count {
+        transpose(image, n);
+        flip_vertically(image, n);
+    }
+}
+
+fn is_rule_matched(image: &[u8], rule: &Rule) -> bool {
+    let patterns = &rule.0;
+    patterns.iter().any(|pattern| pattern == image)
+}
+
+fn augment_rules(rules: &mut [Rule]) {
+    for rule in rules {
+        let patterns = &mut rule.0;
+        let n = usize::isqrt(patterns[0].len());
+
+        let mut augmented = patterns[0].clone();
+        flip_vertically(&mut augmented, n);
+        patterns.push(augmented.clone());
+
+        (1..=3).for_each(|count| {
+            rotate_ccw(&mut augmented, n, count);
+            patterns.push(augmented.clone());
+        });
+
+        let mut augmented = patterns[0].clone();
+        flip_horizontally(&mut augmented, n);
+        patterns.push(augmented.clone());
+
+        (1..=3).for_each(|count| {
+            rotate_ccw(&mut augmented, n, count);
+            patterns.push(augmented.clone());
+        });
+
+        let mut augmented = patterns[0].clone();
+
+        (1..=3).for_each(|count| {
+            rotate_ccw(&mut augmented, n, count);
+            patterns.push(augmented.clone());
+        });
+
+        patterns.sort();
+        patterns.dedup();
+    }
+}
+
+fn split_image(image: &[u8], divisor: usize) -> Vec<Vec<u8>> {
+    let n = usize::isqrt(image.len());
+    let side_count = n / divisor;
+
+    let mut splitted = vec![vec![]; side_count * side_count];
+
+    let mut current = 0;
+
+    for row in 0..side_count {
+        for col in 0..side_count {
+            for i in row * divisor..(row + 1) * divisor {
+                for j in col * divisor..(col + 1) * divisor {
+                    splitted[current].push(image[i * (n + 1) + j]);
+                }
+                splitted[current].push(47);
+            }
+            splitted[current].pop();
+            current += 1;
+        }
+    }
+
+    splitted
+}
+
+fn merge_images(images: &[Vec<u8>], side_count: usize) -> Vec<u8> {
+    let mut merged = vec![];
+
+    let sub_n = usize::isqrt(images[0].len());
+
+    let mut row_start = 0;
+
+    loop {
+        for i in 0..sub_n {
+            for sub_image in images.iter().skip(row_start).take(side_count) {
+                for j in 0..sub_n {
+                    merged.push(sub_image[i * (sub_n + 1) + j]);
+                }
+            }
+            merged.push(47);
+        }
+
+        row_start += side_count;
+
+        if row_start == images.len() {
+            break;
+        }
+    }
+
+    merged
+}
+
+/// Square of the image that none of the rules enhance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoRuleError {
+    /// rows separated by `/`, like the rules
+    pub square: String,
+}
+
+impl fmt::Display for NoRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = self.square.split('/').count();
+        write!(f, "no rule matches a {n}x{n} square `{}`", self.square)
+    }
+}
+
+impl error::Error for NoRuleError {}
+
+fn get_next_image(image: &[u8], rules: &[Rule]) -> Result<Vec<u8>, NoRuleError> {
+    let n = usize::isqrt(image.len());
+    let mut unmatched = None;
+
+    for divisor in [2, 3].iter() {
+        if n.is_multiple_of(*divisor) {
+            let mut sub_images = vec![];
+            let splitted = split_image(image, *divisor);
+
+            for sub_image in splitted {
+                match rules.iter().find(|rule| is_rule_matched(&sub_image, rule)) {
+                    Some(rule) => sub_images.push(rule.1.clone()),
+                    None => {
+                        unmatched = Some(sub_image);
+                        break;
+                    }
+                }
+            }
+            // if not all sub_images matched the rules,
+            // then try next divisor.
+            if sub_images.len() == (n / divisor) * (n / divisor) {
+                return Ok(merge_images(&sub_images, n / divisor));
+            }
+        }
+    }
+
+    // images only grow by rules, so they always split in 2x2 or 3x3 squares.
+    let square = unmatched.unwrap_or_default();
+    Err(NoRuleError {
+        square: String::from_utf8_lossy(&square).into_owned(),
+    })
+}
+
+fn count_on(rules: &[Rule], image: &[u8], iteration_count: usize) -> Result<usize, NoRuleError> {
+    let mut rules = rules.to_owned();
+    // augment rules patterns with rotations and flips, single time,
+    // so we don't need to augment it on every check.
+    augment_rules(&mut rules);
+
+    let mut iteration_count = iteration_count;
+    let mut image = image.to_owned();
+
+    while iteration_count != 0 {
+        image = get_next_image(&image, &rules)?;
+        iteration_count -= 1;
+    }
+
+    Ok(image.iter().filter(|pixel| **pixel == 35).count())
+}
+
+pub fn part1(rules: &[Rule]) -> Result<usize, NoRuleError> {
+    // How many pixels stay on after 5 iterations?
+
+    // .#.
+    // ..#
+    // ###
+
+    // 47 is a new line which looks like that -> "/"
+
+    let image = vec![46, 35, 46, 47, 46, 46, 35, 47, 35, 35, 35];
+
+    count_on(rules, &image, 5)
+}
+
+pub fn part2(rules: &[Rule]) -> Result<usize, NoRuleError> {
+    // How many pixels stay on after 18 iterations?
+
+    let image = vec![46, 35, 46, 47, 46, 46, 35, 47, 35, 35, 35];
+
+    count_on(rules, &image, 18)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Fractal Art";
+
+    type Input = Vec<Rule>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)?))
+    }
+}
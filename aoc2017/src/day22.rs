@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Nodes = HashSet<(i64, i64)>;
+
+enum Direction {
+    Up,
+    Right,
+    Left,
+    Down,
+}
+
+enum Status {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+pub fn parse(input: &str) -> Result<Nodes, ParseError> {
+    let lines: Vec<parse::Token> = parse::lines(input).collect();
+
+    // side of the square, the carrier starts in the middle.
+    let n = lines.len();
+    if n.is_multiple_of(2) {
+        let token = lines.last().copied().unwrap_or(parse::end_of_input(input));
+        return Err(token.error("square grid with odd side"));
+    }
+    let d = n / 2;
+
+    let mut nodes = Nodes::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.text.len() != n {
+            return Err(line.error(format!("row of {n} nodes")));
+        }
+
+        for (j, node) in line.text.char_indices() {
+            match node {
+                '#' => {
+                    let x = j as i64 - d as i64;
+                    let y = i as i64 - d as i64;
+                    nodes.insert((x, y));
+                }
+                '.' => {}
+                _ => {
+                    let token = line.slice(&line.text[j..j + node.len_utf8()]);
+                    return Err(token.error("`.` or `#`"));
+                }
+            }
+        }
+    }
+
+    Ok(nodes)
+}
+
+fn next_coordinates(coordinates: (i64, i64), direction: &Direction) -> (i64, i64) {
+    match direction {
+        Direction::Up => (coordinates.0, coordinates.1 - 1),
+        Direction::Right => (coordinates.0 + 1, coordinates.1),
+        Direction::Down => (coordinates.0, coordinates.1 + 1),
+        Direction::Left => (coordinates.0 - 1, coordinates.1),
+    }
+}
+
+fn next_direction(direction: &Direction, status: &Status) -> Direction {
+    match (direction, status) {
+        (Direction::Up, Status::Clean) => Direction::Left,
+        (Direction::Up, Status::Weakened) => Direction::Up,
+        (Direction::Up, Status::Infected) => Direction::Right,
+        (Direction::Up, Status::Flagged) => Direction::Down,
+
+        (Direction::Down, Status::Clean) => Direction::Right,
+        (Direction::Down, Status::Weakened) => Direction::Down,
+        (Direction::Down, Status::Infected) => Direction::Left,
+        (Direction::Down, Status::Flagged) => Direction::Up,
+
+        (Direction::Left, Status::Clean) => Direction::Down,
+        (Direction::Left, Status::Weakened) => Direction::Left,
+        (Direction::Left, Status::Infected) => Direction::Up,
+        (Direction::Left, Status::Flagged) => Direction::Right,
+
+        (Direction::Right, Status::Clean) => Direction::Up,
+        (Direction::Right, Status::Weakened) => Direction::Right,
+        (Direction::Right, Status::Infected) => Direction::Down,
+        (Direction::Right, Status::Flagged) => Direction::Left,
+    }
+}
+
+pub fn part1(nodes: &Nodes) -> usize {
+    // Given your actual map, after 10000 bursts of activity,
+    // how many bursts cause a node to become infected?
+    // (Do not count nodes that begin infected.)
+
+    let mut infected_nodes = nodes.clone();
+
+    let mut current_direction = Direction::Up;
+    let mut current_coordinates = (0, 0);
+
+    let mut burst_count = 0;
+    let mut infections = 0;
+
+    loop {
+        if infected_nodes.contains(&current_coordinates) {
+            current_direction = next_direction(&current_direction, &Status::Infected);
+            infected_nodes.remove(&current_coordinates);
+        } else {
+            current_direction = next_direction(&current_direction, &Status::Clean);
+            infected_nodes.insert(current_coordinates);
+            infections += 1;
+        }
+
+        current_coordinates = next_coordinates(current_coordinates, &current_direction);
+
+        burst_count += 1;
+
+        if burst_count == 10000 {
+            break;
+        }
+    }
+
+    infections
+}
+
+pub fn part2(nodes: &Nodes) -> usize {
+    // Given your actual map, after 10000000 bursts of activity,
+    // how many bursts cause a node to become infected?
+    // (Do not count nodes that begin infected.)
+
+    let mut infected_nodes = nodes.clone();
+    let mut weakened_nodes = Nodes::new();
+    let mut flagged_nodes = Nodes::new();
+
+    let mut current_direction = Direction::Up;
+    let mut current_coordinates = (0, 0);
+
+    let mut burst_count = 0;
+    let mut infections = 0;
+
+    loop {
+        if infected_nodes.contains(&current_coordinates) {
+            current_direction = next_direction(&current_direction, &Status::Infected);
+            infected_nodes.remove(&current_coordinates);
+            flagged_nodes.insert(current_coordinates);
+        } else if weakened_nodes.contains(&current_coordinates) {
+            current_direction = next_direction(&current_direction, &Status::Weakened);
+            weakened_nodes.remove(&current_coordinates);
+            infected_nodes.insert(current_coordinates);
+            infections += 1;
+        } else if flagged_nodes.contains(&current_coordinates) {
+            current_direction = next_direction(&current_direction, &Status::Flagged);
+            flagged_nodes.remove(&current_coordinates);
+        } else {
+            current_direction = next_direction(&current_direction, &Status::Clean);
+            weakened_nodes.insert(current_coordinates);
+        }
+
+        current_coordinates = next_coordinates(current_coordinates, &current_direction);
+
+        burst_count += 1;
+
+        if burst_count == 10_000_000 {
+            break;
+        }
+    }
+
+    infections
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Sporifica Virus";
+
+    type Input = Nodes;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+#[derive(Debug)]
+pub enum Operand {
+    Register(char),
+    Value(isize),
+}
+
+impl Operand {
+    fn from_token(token: parse::Token) -> Result<Self, ParseError> {
+        match (token.text.parse::<isize>(), token.text.as_bytes()) {
+            (Ok(integer), _) => Ok(Operand::Value(integer)),
+            (Err(..), [name @ b'a'..=b'z']) => Ok(Operand::Register(*name as char)),
+            _ => Err(token.error("register name or integer")),
+        }
+    }
+
+    // operands which are written to
+    fn register_from_token(token: parse::Token) -> Result<Self, ParseError> {
+        match token.text.as_bytes() {
+            [name @ b'a'..=b'z'] => Ok(Operand::Register(*name as char)),
+            _ => Err(token.error("register name")),
+        }
+    }
+
+    fn get_value(&self, registers: &mut Registers) -> isize {
+        match self {
+            Operand::Register(op_name) => *registers.entry(*op_name).or_insert(0),
+            Operand::Value(op_value) => *op_value,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Set(Operand, Operand),
+    Sub(Operand, Operand),
+    Mul(Operand, Operand),
+    Jnz(Operand, Operand),
+}
+
+impl Instruction {
+    fn from_token(line: parse::Token) -> Result<Self, ParseError> {
+        let mut components = line.split_whitespace();
+        let name = components.expect("instruction")?;
+
+        let instruction = match name.text {
+            // two operands
+            "set" => Instruction::Set(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "sub" => Instruction::Sub(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "mul" => Instruction::Mul(
+                Operand::register_from_token(components.expect("register name")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            "jnz" => Instruction::Jnz(
+                Operand::from_token(components.expect("operand")?)?,
+                Operand::from_token(components.expect("operand")?)?,
+            ),
+            _ => return Err(name.error("instruction")),
+        };
+
+        components.finish()?;
+
+        Ok(instruction)
+    }
+
+    fn apply_mut<F>(&self, cpu: &mut CPU<F>)
+    where
+        F: FnMut(&Instruction),
+    {
+        match self {
+            Instruction::Set(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) =
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Sub(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) -=
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Mul(Operand::Register(reg_name), operand) => {
+                *cpu.registers.entry(*reg_name).or_insert(0) *=
+                    operand.get_value(&mut cpu.registers);
+                cpu.pc += 1;
+            }
+            Instruction::Jnz(operand1, operand2) => {
+                let is_jmp = operand1.get_value(&mut cpu.registers) != 0;
+                let offset = operand2.get_value(&mut cpu.registers);
+
+                if is_jmp {
+                    cpu.pc += offset;
+                } else {
+                    cpu.pc += 1;
+                }
+            }
+            instruction => unimplemented!("unknown instruction: {:?}", instruction),
+        }
+    }
+}
+
+type Registers = HashMap<char, isize>;
+
+#[allow(clippy::upper_case_acronyms)]
+struct CPU<F>
+where
+    F: FnMut(&Instruction),
+{
+    registers: Registers,
+    pc: isize,
+    trap: F,
+}
+
+impl<F: FnMut(&Instruction)> CPU<F> {
+    fn new(trap: F) -> Self {
+        CPU {
+            registers: HashMap::new(),
+            pc: 0,
+            trap,
+        }
+    }
+
+    fn eval(&mut self, instructions: &[Instruction]) {
+        loop {
+            let next_instruction = &instructions[self.pc as usize];
+
+            (self.trap)(next_instruction);
+
+            next_instruction.apply_mut(self);
+
+            if self.pc as usize >= instructions.len() {
+                break;
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = parse::lines(input)
+        .map(Instruction::from_token)
+        .collect::<Result<_, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(parse::end_of_input(input).error("instruction"));
+    }
+
+    Ok(instructions)
+}
+
+pub fn part1(instructions: &[Instruction]) -> usize {
+    // How many times is the mul instruction invoked?
+    let mut mul_count = 0;
+
+    let mut cpu = CPU::new(|instruction| {
+        if let Instruction::Mul(..) = instruction {
+            mul_count += 1;
+        }
+    });
+
+    cpu.eval(instructions);
+
+    mul_count
+}
+
+pub fn part2(_instructions: &[Instruction]) -> usize {
+    // After setting register a to 1, if the program were to run to completion,
+    // what value would be left in register h?
+
+    let is_prime = |n| {
+        for i in 2..usize::isqrt(n) + 1 {
+            if n.is_multiple_of(i) {
+                return true;
+            }
+        }
+        false
+    };
+
+    (107900..=124900)
+        .step_by(17)
+        .map(|n| is_prime(n) as usize)
+        .sum()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Coprocessor Conflagration";
+
+    type Input = Vec<Instruction>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
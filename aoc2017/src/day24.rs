@@ -0,0 +1,131 @@
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub type Component = (u32, u32);
+
+fn parse_line(line: parse::Token) -> Result<Component, ParseError> {
+    let mut ports = line.split("/");
+    let a = ports.expect("number of pins")?.parse("number of pins")?;
+    let b = ports
+        .expect("`/` and number of pins")?
+        .parse("number of pins")?;
+    ports.finish()?;
+
+    Ok((a, b))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Component>, ParseError> {
+    let components: Vec<Component> = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    if components.is_empty() {
+        return Err(parse::end_of_input(input).error("component `a/b`"));
+    }
+
+    Ok(components)
+}
+
+fn make_pair(component1: &Component, component2: &Component) -> Option<Component> {
+    if component1.1 == component2.0 {
+        return Some(*component2);
+    }
+    if component1.1 == component2.1 {
+        return Some((component2.1, component2.0));
+    }
+
+    None
+}
+
+fn find_strongest_bridge(bridge: Vec<Component>, components: Vec<Component>, strength: u32) -> u32 {
+    let last = bridge[bridge.len() - 1];
+
+    let mut strengths = vec![];
+
+    for (idx, comp) in components.iter().enumerate() {
+        if let Some(comp) = make_pair(&last, comp) {
+            let mut next_components = components.clone();
+            next_components.remove(idx);
+            let mut next_bridge = bridge.clone();
+            next_bridge.push(comp);
+            strengths.push(find_strongest_bridge(
+                next_bridge,
+                next_components,
+                strength + comp.0 + comp.1,
+            ));
+        }
+    }
+
+    *strengths.iter().max().unwrap_or(&strength)
+}
+
+fn find_longest_bridge(
+    bridge: Vec<Component>,
+    components: Vec<Component>,
+    strength: u32,
+    length: u32,
+) -> (u32, u32) {
+    let last = bridge[bridge.len() - 1];
+
+    let mut strengths = vec![];
+
+    for (idx, comp) in components.iter().enumerate() {
+        if let Some(comp) = make_pair(&last, comp) {
+            let mut next_components = components.clone();
+            next_components.remove(idx);
+            let mut next_bridge = bridge.clone();
+            next_bridge.push(comp);
+            strengths.push(find_longest_bridge(
+                next_bridge,
+                next_components,
+                strength + comp.0 + comp.1,
+                length + 1,
+            ));
+        }
+    }
+
+    if let Some((length, strength)) = strengths.iter().max() {
+        return (*length, *strength);
+    }
+    (length, strength)
+}
+
+pub fn part1(components: &[Component]) -> u32 {
+    // What is the strength of the strongest bridge
+    // you can make with the components you have available?
+    let bridge = vec![(0, 0)];
+
+    find_strongest_bridge(bridge, components.to_vec(), 0)
+}
+
+pub fn part2(components: &[Component]) -> u32 {
+    // What is the strength of the longest bridge you can make?
+    // If you can make multiple bridges of the longest length, pick the strongest one.
+    let bridge = vec![(0, 0)];
+
+    find_longest_bridge(bridge, components.to_vec(), 0, 0).1
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Electromagnetic Moat";
+
+    type Input = Vec<Component>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        Ok(Some(part2(input)))
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::{ParseError, Solution, parse};
+
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_token(token: parse::Token) -> Result<Self, ParseError> {
+        match token.text {
+            "left" => Ok(Direction::Left),
+            "right" => Ok(Direction::Right),
+            _ => Err(token.error("`left` or `right`")),
+        }
+    }
+}
+
+pub struct Action {
+    write_value: u8,
+    next_direction: Direction,
+    next_state: String,
+}
+
+pub struct State {
+    on_zero: Action,
+    on_one: Action,
+}
+
+type Tape = HashMap<i64, u8>;
+pub type States = HashMap<String, State>;
+
+pub type Blueprint = (String, States, usize);
+
+// next line with indentation trimmed.
+fn next_line<'a>(
+    lines: &mut impl Iterator<Item = parse::Token<'a>>,
+    input: &'a str,
+    expected: &str,
+) -> Result<parse::Token<'a>, ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| parse::end_of_input(input).error(expected))?;
+
+    Ok(line.slice(line.text.trim()))
+}
+
+fn parse_action<'a>(
+    lines: &mut impl Iterator<Item = parse::Token<'a>>,
+    input: &'a str,
+    current_value: u8,
+    references: &mut Vec<parse::Token<'a>>,
+) -> Result<Action, ParseError> {
+    let condition = format!("If the current value is {current_value}:");
+    next_line(lines, input, &format!("`{condition}`"))?.expect(&condition)?;
+
+    let write_value =
+        next_line(lines, input, "`- Write the value N.`")?.strip("- Write the value ", ".")?;
+    let write_value = match write_value.text {
+        "0" => 0,
+        "1" => 1,
+        _ => return Err(write_value.error("`0` or `1`")),
+    };
+
+    let next_direction = next_line(lines, input, "`- Move one slot to the DIRECTION.`")?
+        .strip("- Move one slot to the ", ".")?;
+    let next_direction = Direction::from_token(next_direction)?;
+
+    let next_state = next_line(lines, input, "`- Continue with state X.`")?
+        .strip("- Continue with state ", ".")?;
+    references.push(next_state);
+
+    Ok(Action {
+        write_value,
+        next_direction,
+        next_state: next_state.text.to_string(),
+    })
+}
+
+pub fn parse(input: &str) -> Result<Blueprint, ParseError> {
+    let mut lines = parse::lines(input);
+    // states mentioned before they are defined, checked in the end.
+    let mut references = vec![];
+
+    let start_state =
+        next_line(&mut lines, input, "`Begin in state X.`")?.strip("Begin in state ", ".")?;
+    references.push(start_state);
+
+    let steps = next_line(
+        &mut lines,
+        input,
+        "`Perform a diagnostic checksum after N steps.`",
+    )?
+    .strip("Perform a diagnostic checksum after ", " steps.")?
+    .parse("number of steps")?;
+
+    let mut states = States::new();
+
+    while let Some(blank) = lines.next() {
+        if !blank.text.trim().is_empty() {
+            return Err(blank.error("empty line"));
+        }
+
+        let state_name = next_line(&mut lines, input, "`In state X:`")?.strip("In state ", ":")?;
+
+        let on_zero = parse_action(&mut lines, input, 0, &mut references)?;
+        let on_one = parse_action(&mut lines, input, 1, &mut references)?;
+
+        let state = State { on_zero, on_one };
+
+        if states.insert(state_name.text.to_string(), state).is_some() {
+            return Err(state_name.error("name of a state not defined yet"));
+        }
+    }
+
+    if let Some(unknown) = references
+        .iter()
+        .find(|state| !states.contains_key(state.text))
+    {
+        return Err(unknown.error("name of a defined state"));
+    }
+
+    let blueprint = (start_state.text.to_string(), states, steps);
+
+    Ok(blueprint)
+}
+
+pub fn part1(blueprint: &Blueprint) -> usize {
+    // What is the diagnostic checksum it produces once it's working again?
+
+    let mut current_step = 0;
+    let mut tape = Tape::new();
+    let mut tape_pos = 0;
+
+    let mut current_state = &blueprint.0;
+    let states = &blueprint.1;
+
+    loop {
+        let state = states.get(current_state).unwrap();
+
+        let tape_entry = tape.entry(tape_pos).or_insert(0);
+
+        if *tape_entry == 0 {
+            let value = state.on_zero.write_value;
+            *tape_entry = value;
+
+            let direction = &state.on_zero.next_direction;
+            tape_pos += match direction {
+                Direction::Left => -1,
+                Direction::Right => 1,
+            };
+            current_state = &state.on_zero.next_state;
+        } else {
+            let value = state.on_one.write_value;
+            *tape_entry = value;
+
+            let direction = &state.on_one.next_direction;
+            tape_pos += match direction {
+                Direction::Left => -1,
+                Direction::Right => 1,
+            };
+            current_state = &state.on_one.next_state;
+        }
+
+        current_step += 1;
+
+        if current_step == blueprint.2 {
+            break;
+        }
+    }
+
+    tape.values().map(|&v| v as usize).sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "The Halting Problem";
+
+    type Input = Blueprint;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>> {
+        // there is no second puzzle on the last day.
+        Ok(None::<usize>)
+    }
+}
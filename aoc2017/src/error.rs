@@ -0,0 +1,43 @@
+use std::error;
+use std::fmt;
+
+/// Error returned when puzzle input doesn't match the expected format.
+///
+/// `line` and `column` are counted from 1, `token` is the offending part of the line,
+/// empty if something is missing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.token)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
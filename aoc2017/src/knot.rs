@@ -0,0 +1,62 @@
+//! Knot hash, shared by day10 and day14.
+
+const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+#[inline(always)]
+pub fn reverse(list: &mut [u8], position: usize, length: usize) {
+    if length < 2 {
+        return;
+    }
+
+    let end_pos = position + length - 1;
+
+    let mut l = position % list.len();
+    let mut h = end_pos % list.len();
+
+    while l != (position + length / 2) % list.len() {
+        if l != h {
+            // swap
+            list[l] ^= list[h];
+            list[h] ^= list[l];
+            list[l] ^= list[h]
+        }
+        l += 1;
+        l %= list.len();
+        if h == 0 {
+            h = list.len();
+        }
+        h -= 1;
+    }
+}
+
+/// Runs `rounds` rounds of reversals over the `0..=255` list.
+pub fn sparse_hash(lengths: &[usize], rounds: usize) -> Vec<u8> {
+    let mut hash = vec![];
+    (0..=255).for_each(|num| hash.push(num as u8));
+
+    let mut position = 0;
+    let mut skip = 0;
+
+    (0..rounds).for_each(|_| {
+        for length in lengths {
+            reverse(&mut hash, position, *length);
+            position += (*length + skip) % hash.len();
+            skip += 1;
+        }
+    });
+
+    hash
+}
+
+/// Full 64-round knot hash of `input` bytes, densified to 16 bytes.
+pub fn knot_hash(input: &[u8]) -> Vec<u8> {
+    let mut lengths: Vec<usize> = input.iter().copied().map(|v| v as usize).collect();
+
+    lengths.extend(SUFFIX);
+
+    sparse_hash(&lengths, 64)
+        .chunks(16)
+        // densify [256] -> [16]
+        .map(|chunk| chunk.iter().copied().reduce(|acc, e| acc ^ e).unwrap())
+        .collect()
+}
@@ -0,0 +1,66 @@
+//! Advent of Code 2017 solutions.
+//!
+//! Every day lives in its own module and exposes `parse`, `part1` and `part2`,
+//! both as free functions and through the [`Solution`] trait.
+//! The `dayNN` binaries are thin wrappers around them.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+pub mod answers;
+pub mod bigint;
+pub mod cycle;
+mod error;
+pub mod knot;
+pub mod multiset;
+pub mod parse;
+pub mod registry;
+mod solution;
+pub mod windows;
+
+pub use error::ParseError;
+pub use solution::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Reads the file given as the first cli argument, or stdin for `-`, and parses it as `S` input.
+pub fn read_input<S: Solution>() -> Result<S::Input, Box<dyn std::error::Error>> {
+    let input_file = env::args()
+        .nth(1)
+        .ok_or("no input file as cli argument is provided")?;
+
+    let input = if input_file == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        fs::read_to_string(&input_file)?
+    };
+
+    S::parse(&input).map_err(|err| format!("{input_file}: {err}").into())
+}
@@ -0,0 +1,215 @@
+//! Counting collections, equal when they hold the same items the same number of times.
+//!
+//! [`Multiset`] counts any hashable items, [`LetterMultiset`] counts lowercase letters
+//! in a 26 byte array, cheap to build, compare and hash, e.g. as an anagram key.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multiset<T: Eq + Hash> {
+    // items with zero count are never stored
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Default for Multiset<T> {
+    fn default() -> Self {
+        Multiset {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> Multiset<T> {
+    pub fn new() -> Self {
+        Multiset::default()
+    }
+
+    pub fn insert(&mut self, item: T) {
+        *self.counts.entry(item).or_insert(0) += 1;
+    }
+
+    /// Removes one copy of `item`, `false` if there was none.
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.counts.get_mut(item) {
+            Some(1) => {
+                self.counts.remove(item);
+                true
+            }
+            Some(count) => {
+                *count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of items, copies included.
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Distinct items with their counts, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, count)| (item, *count))
+    }
+
+    /// Every item of `self` is in `other` at least as many times.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.iter().all(|(item, count)| count <= other.count(item))
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+impl<T: Eq + Hash + Clone> Multiset<T> {
+    /// Largest count of every item.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for (item, count) in other.iter() {
+            let union_count = union.counts.entry(item.clone()).or_insert(0);
+            *union_count = count.max(*union_count);
+        }
+        union
+    }
+
+    /// Smallest count of every item.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let counts = self
+            .iter()
+            .map(|(item, count)| (item.clone(), count.min(other.count(item))))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        Multiset { counts }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Multiset<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut multiset = Multiset::new();
+        for item in items {
+            multiset.insert(item);
+        }
+        multiset
+    }
+}
+
+impl<T: Eq + Hash> Hash for Multiset<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the map has no order, so entries are hashed on their own and summed up
+        let sum = self
+            .counts
+            .iter()
+            .map(|entry| {
+                let mut hasher = DefaultHasher::new();
+                entry.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0u64, u64::wrapping_add);
+
+        state.write_u64(sum);
+    }
+}
+
+/// [`Multiset`] of lowercase ASCII letters, a count of up to 255 for each of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LetterMultiset {
+    // `a` first
+    counts: [u8; 26],
+}
+
+impl LetterMultiset {
+    pub fn new() -> Self {
+        LetterMultiset::default()
+    }
+
+    /// `None` if a byte isn't a lowercase letter or comes more than 255 times.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut multiset = LetterMultiset::new();
+        bytes
+            .iter()
+            .all(|&letter| multiset.insert(letter))
+            .then_some(multiset)
+    }
+
+    /// `false` if `letter` isn't a lowercase letter or is already in 255 times.
+    pub fn insert(&mut self, letter: u8) -> bool {
+        match self.slot(letter).map(|count| count.checked_add(1)) {
+            Some(Some(count)) => {
+                self.counts[(letter - b'a') as usize] = count;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn remove(&mut self, letter: u8) -> bool {
+        match self.slot(letter) {
+            Some(0) | None => false,
+            Some(_) => {
+                self.counts[(letter - b'a') as usize] -= 1;
+                true
+            }
+        }
+    }
+
+    fn slot(&self, letter: u8) -> Option<u8> {
+        letter
+            .is_ascii_lowercase()
+            .then(|| self.counts[(letter - b'a') as usize])
+    }
+
+    pub fn count(&self, letter: u8) -> usize {
+        self.slot(letter).unwrap_or(0) as usize
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.iter().map(|&count| count as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|&count| count == 0)
+    }
+
+    /// Distinct letters with their counts, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, usize)> {
+        (b'a'..=b'z')
+            .map(|letter| (letter, self.count(letter)))
+            .filter(|(_, count)| *count > 0)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.counts.iter().zip(&other.counts).all(|(a, b)| a <= b)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    fn zip_with(&self, other: &Self, f: fn(u8, u8) -> u8) -> Self {
+        let mut counts = [0; 26];
+        for (idx, count) in counts.iter_mut().enumerate() {
+            *count = f(self.counts[idx], other.counts[idx]);
+        }
+        LetterMultiset { counts }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, u8::max)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, u8::min)
+    }
+}
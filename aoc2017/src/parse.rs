@@ -0,0 +1,140 @@
+//! Position-aware helpers for parsing puzzle input.
+//!
+//! A [`Token`] is a slice of the input which remembers where it came from,
+//! so every split keeps line and column numbers for [`ParseError`].
+
+use std::str::{self, FromStr};
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Lines of the input as tokens, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Token<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Token {
+        text,
+        line: idx + 1,
+        column: 1,
+    })
+}
+
+/// Position right after the last line, for errors about missing lines.
+pub fn end_of_input(input: &str) -> Token<'_> {
+    Token {
+        text: &input[input.len()..],
+        line: input.lines().count() + 1,
+        column: 1,
+    }
+}
+
+/// The only line of the input with surrounding whitespace trimmed,
+/// trailing blank lines are ignored.
+pub fn single_line(input: &str) -> Result<Token<'_>, ParseError> {
+    let mut lines = lines(input);
+
+    let line = lines.next().unwrap_or_else(|| end_of_input(input));
+    if let Some(extra) = lines.find(|line| !line.text.trim().is_empty()) {
+        return Err(extra.error("end of input"));
+    }
+
+    Ok(line.slice(line.text.trim()))
+}
+
+impl<'a> Token<'a> {
+    /// Wraps `text`, which must be a subslice of this token, with its position.
+    pub fn slice(&self, text: &'a str) -> Token<'a> {
+        let offset = text.as_ptr() as usize - self.text.as_ptr() as usize;
+        assert!(offset + text.len() <= self.text.len(), "not a subslice");
+
+        Token {
+            text,
+            line: self.line,
+            column: self.column + self.text[..offset].chars().count(),
+        }
+    }
+
+    /// Empty token right after the last character.
+    pub fn end(&self) -> Token<'a> {
+        self.slice(&self.text[self.text.len()..])
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, self.text, expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /// Checks that token is exactly `literal`.
+    pub fn expect(&self, literal: &str) -> Result<(), ParseError> {
+        if self.text == literal {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{literal}`")))
+        }
+    }
+
+    /// Strips `prefix` and `suffix`, returning what's left in between.
+    pub fn strip(&self, prefix: &str, suffix: &str) -> Result<Token<'a>, ParseError> {
+        let expected = || format!("`{prefix}...{suffix}`");
+
+        let rest = self
+            .text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(expected()))?;
+        let inner = rest
+            .strip_suffix(suffix)
+            .ok_or_else(|| self.error(expected()))?;
+
+        Ok(self.slice(inner))
+    }
+
+    pub fn split_whitespace(&self) -> Tokens<'a, str::SplitWhitespace<'a>> {
+        Tokens {
+            parent: *self,
+            iter: self.text.split_whitespace(),
+        }
+    }
+
+    pub fn split<'s>(&self, separator: &'s str) -> Tokens<'a, str::Split<'a, &'s str>> {
+        Tokens {
+            parent: *self,
+            iter: self.text.split(separator),
+        }
+    }
+}
+
+/// Parts of a split token, each one keeps its own position.
+pub struct Tokens<'a, I> {
+    parent: Token<'a>,
+    iter: I,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Tokens<'a, I> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|text| self.parent.slice(text))
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Tokens<'a, I> {
+    /// Next part, or an error at the end of the parent token if there is none.
+    pub fn expect(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+        self.next().ok_or_else(|| self.parent.end().error(expected))
+    }
+
+    /// Checks that nothing is left.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(token) => Err(token.error("end of line")),
+            None => Ok(()),
+        }
+    }
+}
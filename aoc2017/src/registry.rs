@@ -0,0 +1,130 @@
+//! Table of all solved days, used by the `aoc` runner.
+
+use std::error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// Answers of a single run, parts the day doesn't have are skipped.
+pub struct Report {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers.iter().find(|answer| answer.part == part)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        let parts_time: Duration = self.answers.iter().map(|answer| answer.time).sum();
+        self.parse_time + parts_time
+    }
+}
+
+/// Parses `input` once and solves requested `parts` of `S`, stops at the first error.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, Box<dyn error::Error>> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut answers = vec![];
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => Some(S::part1(&input)?.to_string()),
+            Part::Two => S::part2(&input)?.map(|answer| answer.to_string()),
+        };
+        let time = start.elapsed();
+
+        if let Some(answer) = answer {
+            answers.push(Answer { part, answer, time });
+        }
+    }
+
+    Ok(Report {
+        parse_time,
+        answers,
+    })
+}
+
+/// Type-erased [`run`] of a single day.
+pub type Runner = fn(&str, &[Part]) -> Result<Report, Box<dyn error::Error>>;
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub run: Runner,
+}
+
+impl Day {
+    /// Directory with the day's `input.txt` and `sample.txt`.
+    pub fn dir(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            number: <$solution>::DAY,
+            title: <$solution>::TITLE,
+            run: run::<$solution>,
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day08::Day08),
+    day!(day09::Day09),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day19::Day19),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,21 @@
+use std::error;
+use std::fmt;
+
+/// Common interface of all days.
+///
+/// `parse` turns raw puzzle input into `Input`, which is shared by both parts.
+/// The parts fail on input that parses but has no answer.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Error: error::Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<impl fmt::Display, Box<dyn error::Error>>;
+
+    /// `None` if the day has no second puzzle.
+    fn part2(input: &Self::Input) -> Result<Option<impl fmt::Display>, Box<dyn error::Error>>;
+}
@@ -0,0 +1,44 @@
+//! Sliding windows over any iterator, a stable stand-in for `Iterator::map_windows`.
+
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I, const N: usize> Iterator for Windows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            None => {
+                let items: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+                self.window = Some(items.try_into().ok()?);
+            }
+            Some(window) => {
+                let item = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = item;
+            }
+        }
+
+        self.window.clone()
+    }
+}
+
+pub trait WindowsExt: Iterator + Sized {
+    /// Overlapping windows of `N` consecutive items, like `slice::windows`.
+    fn windows<const N: usize>(self) -> Windows<Self, N> {
+        assert!(N > 0, "window size must be non-zero");
+
+        Windows {
+            iter: self,
+            window: None,
+        }
+    }
+}
+
+impl<I: Iterator> WindowsExt for I {}
@@ -0,0 +1,43 @@
+//! Cycle detection.
+
+use aoc2017::cycle;
+
+// 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+fn rho(x: &u32) -> u32 {
+    if *x == 5 { 3 } else { x + 1 }
+}
+
+#[test]
+fn tail_and_loop() {
+    assert_eq!(cycle::hashing(0, rho), (3, 3));
+    assert_eq!(cycle::brent(0, rho), (3, 3));
+
+    assert_eq!(cycle::hashing(4, rho), (0, 3));
+    assert_eq!(cycle::brent(4, rho), (0, 3));
+}
+
+#[test]
+fn fixed_point() {
+    assert_eq!(cycle::hashing(7, |x: &u32| *x), (0, 1));
+    assert_eq!(cycle::brent(7, |x: &u32| *x), (0, 1));
+}
+
+#[test]
+fn modes_agree() {
+    for seed in 0..50u64 {
+        let step = |x: &u64| (x * x + seed) % 1009;
+        assert_eq!(
+            cycle::hashing(seed, step),
+            cycle::brent(seed, step),
+            "{seed}"
+        );
+    }
+}
+
+#[test]
+fn nth_state() {
+    let found = cycle::brent(0, rho);
+    let states: Vec<u32> = (0..20).map(|n| cycle::nth(0, rho, found, n)).collect();
+    assert_eq!(states[..8], [0, 1, 2, 3, 4, 5, 3, 4]);
+    assert_eq!(cycle::nth(0, rho, found, 1_000_000_000), 4);
+}
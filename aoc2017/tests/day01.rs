@@ -0,0 +1,164 @@
+//! Captchas at any offset, streamed and in memory.
+
+use aoc2017::day01::{self, StreamError};
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+/// Hands out a byte at a time, interrupted before every other one.
+struct Trickle<'a> {
+    bytes: Cursor<&'a [u8]>,
+    interrupt: bool,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let len = buf.len().min(1);
+        self.bytes.read(&mut buf[..len])
+    }
+}
+
+impl Seek for Trickle<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.bytes.seek(pos)
+    }
+}
+
+fn digits(len: usize) -> String {
+    // repeats often enough for both sums
+    (0..len)
+        .map(|idx| char::from(b'0' + ((idx * idx / 3) % 10) as u8))
+        .collect()
+}
+
+fn stream_error(input: &str) -> String {
+    day01::stream_half_way_sum(Cursor::new(input))
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn same_as_in_memory() {
+    // odd and even lengths, across the 64 KiB chunks
+    for len in [1, 2, 3, 4, 7, 10, 65_535, 65_536, 65_537, 200_001] {
+        let input = digits(len);
+        let parsed = day01::parse(&input).unwrap();
+        let (part1, part2) = (day01::part1(&parsed) as u64, day01::part2(&parsed) as u64);
+
+        assert_eq!(
+            day01::stream_next_digit_sum(input.as_bytes()).unwrap(),
+            part1
+        );
+        assert_eq!(
+            day01::stream_half_way_sum(Cursor::new(input)).unwrap(),
+            part2
+        );
+    }
+}
+
+#[test]
+fn puzzle_examples() {
+    for (input, sum) in [("1122", 3), ("1111", 4), ("1234", 0), ("91212129", 9)] {
+        assert_eq!(day01::stream_next_digit_sum(input.as_bytes()).unwrap(), sum);
+    }
+    for (input, sum) in [("1212", 6), ("1221", 0), ("123425", 4), ("123123", 12)] {
+        assert_eq!(day01::stream_half_way_sum(Cursor::new(input)).unwrap(), sum);
+    }
+}
+
+#[test]
+fn small_reads() {
+    let input = digits(1001);
+    let parsed = day01::parse(&input).unwrap();
+    let trickle = || Trickle {
+        bytes: Cursor::new(input.as_bytes()),
+        interrupt: false,
+    };
+
+    assert_eq!(
+        day01::stream_next_digit_sum(trickle()).unwrap(),
+        day01::part1(&parsed) as u64
+    );
+    assert_eq!(
+        day01::stream_half_way_sum(trickle()).unwrap(),
+        day01::part2(&parsed) as u64
+    );
+}
+
+#[test]
+fn trailing_whitespace() {
+    for input in ["1212\n", "1212 \n", "1212\r\n", "1212\n\n", "1212\t"] {
+        assert_eq!(day01::stream_half_way_sum(Cursor::new(input)).unwrap(), 6);
+        assert_eq!(day01::stream_next_digit_sum(input.as_bytes()).unwrap(), 0);
+    }
+}
+
+#[test]
+fn bad_bytes() {
+    assert_eq!(
+        stream_error("12a4"),
+        "line 1, column 3: expected digit, found `a`"
+    );
+    assert_eq!(
+        stream_error("12 34"),
+        "line 1, column 4: expected end of input, found `3`"
+    );
+    assert_eq!(
+        stream_error("1234\n\n5"),
+        "line 3, column 1: expected end of input, found `5`"
+    );
+    assert_eq!(
+        stream_error("\n"),
+        "line 2, column 1: expected sequence of digits, found nothing"
+    );
+    assert_eq!(
+        stream_error(""),
+        "line 1, column 1: expected sequence of digits, found nothing"
+    );
+
+    let err = day01::stream_next_digit_sum(&b"12\xff"[..]).unwrap_err();
+    assert!(matches!(err, StreamError::Parse(_)));
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 3: expected digit, found `\u{fffd}`"
+    );
+}
+
+#[test]
+fn captchas_at_offsets() {
+    let digits = day01::parse("123123").unwrap();
+
+    assert_eq!(day01::captcha(&digits, 3), 12);
+    assert_eq!(day01::captcha(&digits, 1), 0);
+    // a full turn matches every digit
+    assert_eq!(day01::captcha(&digits, 6), 12);
+    assert_eq!(day01::captchas(&digits, &[1, 3, 6]), [0, 12, 12]);
+    assert_eq!(day01::captchas(&digits, &[]), Vec::<u32>::new());
+
+    for input in ["1122", "91212129", "1212", "123425", "7"] {
+        let digits = day01::parse(input).unwrap();
+        assert_eq!(day01::part1(&digits), day01::captcha(&digits, 1), "{input}");
+        assert_eq!(
+            day01::part2(&digits),
+            day01::captcha(&digits, digits.len() / 2),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn sweep_every_offset() {
+    let digits = day01::parse("1212").unwrap();
+    assert_eq!(day01::sweep(&digits), [(1, 0), (2, 6), (3, 0)]);
+
+    let digits = day01::parse("5").unwrap();
+    assert_eq!(day01::sweep(&digits), []);
+
+    let digits = day01::parse("91212129").unwrap();
+    let sweep = day01::sweep(&digits);
+    assert_eq!(sweep.len(), 7);
+    assert_eq!(sweep[0], (1, day01::part1(&digits)));
+    assert_eq!(sweep[3], (4, day01::part2(&digits)));
+}
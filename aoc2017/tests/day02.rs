@@ -0,0 +1,212 @@
+//! Row checksums and spreadsheet reading.
+
+use aoc2017::day02::{self, Checksum, ChecksumError, Reader, RowChecksum, RowError};
+use aoc2017::registry::{self, Part};
+
+// a checksum outside the built-in ones
+struct Max;
+
+impl RowChecksum<u32> for Max {
+    fn name(&self) -> &str {
+        "max"
+    }
+
+    fn row(&self, row: &[u32]) -> Result<u32, RowError> {
+        row.iter().copied().max().ok_or(RowError::Missing)
+    }
+}
+
+#[test]
+fn built_in_checksums() {
+    let table = vec![vec![5, 1, 9, 5], vec![7, 5, 3], vec![2, 4, 6, 8]];
+    assert_eq!(day02::checksum(&table, &Checksum::Range), Ok(18));
+    assert_eq!(day02::checksum(&table, &Checksum::Sum), Ok(55));
+
+    let table = vec![vec![5, 9, 2, 8], vec![9, 4, 7, 3], vec![3, 8, 6, 5]];
+    assert_eq!(day02::part2(&table), Ok(9));
+}
+
+#[test]
+fn custom_checksum() {
+    let table = vec![vec![5, 1, 9, 5], vec![7, 5, 3]];
+    assert_eq!(day02::checksum(&table, &Max), Ok(16));
+
+    let checksums: [&dyn RowChecksum<u32>; 2] = [&Max, &Checksum::Range];
+    let sums: Vec<u32> = checksums
+        .iter()
+        .map(|checksum| day02::checksum(&table, *checksum).unwrap())
+        .collect();
+    assert_eq!(sums, [16, 12]);
+
+    let err = day02::checksum(&[vec![1], vec![]], &Max).unwrap_err();
+    assert_eq!(err.to_string(), "row 2: no max checksum");
+}
+
+#[test]
+fn error_names_the_row() {
+    let table = vec![vec![8, 4], vec![5, 9], vec![7, 3]];
+    assert_eq!(
+        day02::part2(&table),
+        Err(ChecksumError {
+            row: 2,
+            checksum: "quotient".to_string(),
+            error: RowError::Missing,
+        })
+    );
+    assert_eq!(
+        day02::part2(&table).unwrap_err().to_string(),
+        "row 2: no quotient checksum, there is no evenly divisible pair"
+    );
+
+    let day = registry::find(2).unwrap();
+    let err = (day.run)("5 9\n7 3\n", &[Part::One, Part::Two])
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "row 1: no quotient checksum, there is no evenly divisible pair"
+    );
+}
+
+#[test]
+fn quotients_by_magnitude() {
+    let quotient = |row: &[i64]| Checksum::Quotient.row(row);
+
+    assert_eq!(quotient(&[-8, 4, 3]), Ok(-2));
+    assert_eq!(quotient(&[3, -9, 5]), Ok(-3));
+    assert_eq!(quotient(&[4, 4]), Ok(1));
+    assert_eq!(quotient(&[-4, 7, 4]), Ok(-1));
+    assert_eq!(quotient(&[0, 5, 0]), Err(RowError::Missing));
+    assert_eq!(quotient(&[0, 7, 3]), Err(RowError::Missing));
+    assert_eq!(quotient(&[4]), Err(RowError::Missing));
+    assert_eq!(quotient(&[i64::MIN, 3, 1 << 40]), Ok(-(1 << 23)));
+    assert_eq!(quotient(&[i64::MIN, -1]), Err(RowError::Overflow));
+    assert_eq!(Checksum::Quotient.row(&[4u32, 4]), Ok(1));
+}
+
+#[test]
+fn overflows_name_the_row() {
+    let table = vec![vec![5i64, 1], vec![5, i64::MIN, i64::MAX]];
+    assert_eq!(
+        day02::checksum(&table, &Checksum::Range).unwrap_err(),
+        ChecksumError {
+            row: 2,
+            checksum: "range".to_string(),
+            error: RowError::Overflow,
+        }
+    );
+    assert_eq!(
+        day02::checksum(&table, &Checksum::Range)
+            .unwrap_err()
+            .to_string(),
+        "row 2: range checksum overflows"
+    );
+
+    let row = [i64::MIN, i64::MAX];
+    assert_eq!(Checksum::Median.row(&row), Err(RowError::Overflow));
+    assert_eq!(Checksum::Median.row(&[i64::MIN, 0, i64::MAX]), Ok(0));
+    assert_eq!(Checksum::Gcd.row(&[i64::MIN]), Err(RowError::Overflow));
+    assert_eq!(Checksum::Gcd.row(&[i64::MIN, -1]), Ok(1));
+    assert_eq!(Checksum::Gcd.row(&[i64::MIN, 6]), Ok(2));
+    assert_eq!(Checksum::Sum.row(&[i64::MAX, 1]), Err(RowError::Overflow));
+    assert_eq!(
+        Checksum::Sum.row(&[i64::MAX, 1, -1]),
+        Err(RowError::Overflow)
+    );
+
+    // each range fits, their total doesn't
+    let table = vec![vec![u32::MAX, 1], vec![u32::MAX, 1]];
+    assert_eq!(
+        day02::part1(&table).unwrap_err().to_string(),
+        "row 2: range checksum overflows"
+    );
+
+    let day = registry::find(2).unwrap();
+    let err = (day.run)("4294967295 1\n4294967295 1\n", &[Part::One])
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "row 2: range checksum overflows");
+}
+
+fn read_error<T: day02::Cell>(reader: &Reader, input: &str) -> String {
+    reader.read::<T>(input).err().unwrap().to_string()
+}
+
+#[test]
+fn reader_delimiters() {
+    let whitespace = Reader::new();
+    assert_eq!(
+        whitespace.read("5 1\t9  5\n7 5 3\n"),
+        Ok(vec![vec![5u32, 1, 9, 5], vec![7, 5, 3]])
+    );
+
+    let csv = Reader::new().delimiter(',');
+    assert_eq!(
+        csv.read("5, 1,9\n7 ,5,3\n"),
+        Ok(vec![vec![5u32, 1, 9], vec![7, 5, 3]])
+    );
+
+    let tsv = Reader::new().delimiter('\t');
+    assert_eq!(
+        tsv.read("5\t1\n7\t 5\n"),
+        Ok(vec![vec![5u32, 1], vec![7, 5]])
+    );
+}
+
+#[test]
+fn reader_header() {
+    let reader = Reader::new().delimiter(',').skip_header(true);
+    assert_eq!(
+        reader.read("a,b\n1,2\n3,4\n"),
+        Ok(vec![vec![1u32, 2], vec![3, 4]])
+    );
+    assert_eq!(reader.read::<u32>("a,b\n"), Ok(vec![]));
+
+    // rows count without the header, the same as checksum errors
+    assert_eq!(
+        read_error::<u32>(&reader, "a,b\n1,2\n3,x\n"),
+        "line 3, column 3: expected unsigned integer in row 2, cell 2, found `x`"
+    );
+    let table: Vec<Vec<u32>> = reader.read("a,b\n4,2\n3,5\n").unwrap();
+    assert_eq!(day02::part2(&table).unwrap_err().row, 2);
+}
+
+#[test]
+fn reader_bad_cells() {
+    let csv = Reader::new().delimiter(',');
+    assert_eq!(
+        read_error::<u32>(&csv, "1,2\n3,,4\n"),
+        "line 2, column 3: expected unsigned integer in row 2, cell 2, found nothing"
+    );
+    assert_eq!(
+        read_error::<u32>(&csv, "1,2\n, ,\n"),
+        "line 2, column 1: expected row of numbers, found `, ,`"
+    );
+    assert_eq!(
+        read_error::<u32>(&Reader::new(), "1 2\n\n"),
+        "line 2, column 1: expected row of numbers, found nothing"
+    );
+    assert_eq!(
+        read_error::<i64>(&Reader::new(), "1 2.5\n"),
+        "line 1, column 3: expected integer in row 1, cell 2, found `2.5`"
+    );
+}
+
+#[test]
+fn reader_cell_types() {
+    let reader = Reader::new();
+    assert_eq!(reader.read("-3 4\n"), Ok(vec![vec![-3i64, 4]]));
+    assert_eq!(reader.read("-3 4\n"), Ok(vec![vec![-3i32, 4]]));
+    assert_eq!(
+        read_error::<u64>(&reader, "-3 4\n"),
+        "line 1, column 1: expected unsigned integer in row 1, cell 1, found `-3`"
+    );
+
+    let big = "18446744073709551615 1\n";
+    assert_eq!(reader.read(big), Ok(vec![vec![u64::MAX, 1]]));
+    assert!(reader.read::<u32>(big).is_err());
+
+    let table: Vec<Vec<i64>> = reader.read("-3 4 9\n10 -2\n").unwrap();
+    assert_eq!(day02::checksum(&table, &Checksum::Range), Ok(12 + 12));
+    assert_eq!(day02::checksum(&table, &Checksum::Gcd), Ok(1 + 2));
+}
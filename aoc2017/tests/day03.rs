@@ -0,0 +1,176 @@
+//! Round-trip properties of the spiral address/coordinates conversion, and rendering.
+
+use aoc2017::bigint::BigUint;
+use aoc2017::day03::{self, Layout, Neighbourhood, Point, Spiral, address, coordinates};
+
+// xorshift, enough to spread samples over the whole `u64` range.
+fn random_numbers(mut state: u64) -> impl Iterator<Item = u64> {
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+#[test]
+fn small_spiral() {
+    // 5 4 3
+    // 6 1 2
+    // 7 8 9
+    let squares: [(u64, Point); 9] = [
+        (1, (0, 0)),
+        (2, (1, 0)),
+        (3, (1, -1)),
+        (4, (0, -1)),
+        (5, (-1, -1)),
+        (6, (-1, 0)),
+        (7, (-1, 1)),
+        (8, (0, 1)),
+        (9, (1, 1)),
+    ];
+
+    for (addr, point) in squares {
+        assert_eq!(coordinates(addr), point, "square {addr}");
+        assert_eq!(address(point), Some(addr), "point {point:?}");
+    }
+}
+
+#[test]
+fn address_round_trip() {
+    let first = 1..=10_000;
+    let edges = [u64::MAX, u64::MAX - 1, (1 << 32) - 1, 1 << 32, 1 << 63];
+    let random = random_numbers(0x2545_f491_4f6c_dd1d).take(100_000);
+
+    for addr in first.chain(edges).chain(random) {
+        let addr = addr.max(1);
+        assert_eq!(address(coordinates(addr)), Some(addr), "square {addr}");
+    }
+}
+
+#[test]
+fn coordinates_round_trip() {
+    let mut numbers = random_numbers(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..100_000 {
+        // both small and huge rings
+        let shift = numbers.next().unwrap() % 64;
+        let x = numbers.next().unwrap() as i64 >> shift;
+        let y = numbers.next().unwrap() as i64 >> shift;
+
+        match address((x, y)) {
+            Some(addr) => assert_eq!(coordinates(addr), (x, y), "square {addr}"),
+            // only the outermost rings don't fit
+            None => assert!(x.unsigned_abs().max(y.unsigned_abs()) >= 1 << 31),
+        }
+    }
+}
+
+#[test]
+fn neighbouring_squares() {
+    // consecutive squares are always next to each other
+    for addr in random_numbers(7)
+        .take(10_000)
+        .map(|addr| addr.clamp(1, u64::MAX - 1))
+    {
+        let (x1, y1) = coordinates(addr);
+        let (x2, y2) = coordinates(addr + 1);
+        assert_eq!(x1.abs_diff(x2) + y1.abs_diff(y2), 1, "square {addr}");
+    }
+}
+
+#[test]
+fn stress_test_values() {
+    // 147  142  133  122   59
+    // 304    5    4    2   57
+    // 330   10    1    1   54
+    // 351   11   23   25   26
+    // 362  747  806--->   ...
+    let expected = [
+        1, 1, 2, 4, 5, 10, 11, 23, 25, 26, 54, 57, 59, 122, 133, 142, 147, 304, 330, 351, 362, 747,
+        806,
+    ];
+    let values: Vec<u64> = Spiral::new(Neighbourhood::Eight)
+        .take(expected.len())
+        .map(|(_, _, value)| value)
+        .collect();
+
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn stress_test_overflow() {
+    let checked: Vec<(u64, Point, u64)> = Spiral::new(Neighbourhood::Eight).collect();
+    let big: Vec<(u64, Point, BigUint)> = Spiral::new(Neighbourhood::Eight)
+        .take(checked.len() + 1)
+        .collect();
+
+    // the checked spiral ends right before the first value that doesn't fit
+    for ((addr, point, value), (big_addr, big_point, big_value)) in checked.iter().zip(&big) {
+        assert_eq!((addr, point), (big_addr, big_point));
+        assert_eq!(value.to_string(), big_value.to_string());
+    }
+    assert!(big.last().unwrap().2 > BigUint::from(u64::MAX));
+}
+
+#[test]
+fn table_layouts() {
+    assert_eq!(
+        day03::render_table(2, Layout::Address),
+        "\
+17  16  15  14  13
+18   5   4   3  12
+19   6   1   2  11
+20   7   8   9  10
+21  22  23  24  25
+"
+    );
+
+    assert_eq!(
+        day03::render_table(2, "sum4".parse().unwrap()),
+        "\
+12  12  10   8   7
+14   2   2   1   7
+17   3   1   1   6
+20   3   4   5   5
+20  23  27  32  37
+"
+    );
+
+    // the table from the puzzle
+    assert_eq!(
+        day03::render_table(2, "sum".parse().unwrap()),
+        "\
+147  142  133  122   59
+304    5    4    2   57
+330   10    1    1   54
+351   11   23   25   26
+362  747  806  880  931
+"
+    );
+
+    assert_eq!(day03::render_table(0, Layout::Address), "1\n");
+}
+
+#[test]
+fn ppm_image() {
+    let mut image = vec![];
+    day03::write_ppm(&mut image, 2, Layout::Address).unwrap();
+
+    let header = b"P6\n5 5\n255\n";
+    assert!(image.starts_with(header));
+    assert_eq!(image.len(), header.len() + 5 * 5 * 3);
+
+    // row by row, smallest address in the centre, largest in the bottom right corner
+    let pixel = |col: usize, row: usize| {
+        let start = header.len() + 3 * (5 * row + col);
+        image[start..start + 3].to_vec()
+    };
+    assert_eq!(pixel(2, 2), [0, 0, 96]);
+    assert_eq!(pixel(4, 4), [224, 0, 0]);
+
+    let mut image = vec![];
+    day03::write_ppm(&mut image, 40, Layout::Sum(Neighbourhood::Eight)).unwrap();
+    assert!(image.starts_with(b"P6\n81 81\n255\n"));
+    assert_eq!(image.len(), b"P6\n81 81\n255\n".len() + 81 * 81 * 3);
+}
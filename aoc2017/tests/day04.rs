@@ -0,0 +1,91 @@
+//! Passphrase policy rules.
+
+use aoc2017::day04::{CharClass, Policy, Rule, Violation};
+
+fn words(passphrase: &str) -> Vec<String> {
+    passphrase.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn puzzle_examples() {
+    let policy = Policy::new().rule(Rule::UniqueWords).rule(Rule::NoAnagrams);
+
+    assert!(policy.check(&words("abcde fghij")).is_ok());
+    assert!(policy.check(&words("iiii oiii ooii oooi oooo")).is_ok());
+    assert_eq!(
+        policy.check(&words("abcde xyz ecdab")),
+        Err(Violation {
+            rule: Rule::NoAnagrams,
+            words: words("abcde ecdab"),
+        })
+    );
+    assert_eq!(
+        policy.check(&words("aa bb aa")).unwrap_err().rule,
+        Rule::UniqueWords
+    );
+}
+
+#[test]
+fn composed_rules() {
+    let policy = Policy::new()
+        .rule(Rule::MinWords(3))
+        .rule(Rule::MinLength(2))
+        .rule(Rule::AllowedChars(vec![
+            CharClass::Lowercase,
+            CharClass::Digit,
+        ]))
+        .rule(Rule::NoSubstrings);
+
+    assert!(policy.check(&words("ab cd ef9")).is_ok());
+    assert_eq!(
+        policy.check(&words("ab cd")).unwrap_err().rule,
+        Rule::MinWords(3)
+    );
+    assert_eq!(policy.check(&words("ab c de")).unwrap_err().words, ["c"]);
+    assert_eq!(policy.check(&words("ab Cd de")).unwrap_err().words, ["Cd"]);
+    assert_eq!(
+        policy.check(&words("ab xaby de")).unwrap_err().words,
+        ["ab", "xaby"]
+    );
+}
+
+#[test]
+fn substrings() {
+    let substring = |passphrase: &str| Rule::NoSubstrings.check(&words(passphrase));
+
+    assert_eq!(substring("xaby de ab").unwrap(), ["ab", "xaby"]);
+    assert_eq!(substring("abc bcd abc").unwrap(), ["abc", "abc"]);
+    assert_eq!(substring("abcd b bc").unwrap(), ["b", "bc"]);
+    assert_eq!(substring("abc bca cab"), None);
+    assert_eq!(substring("a"), None);
+    assert_eq!(substring(""), None);
+}
+
+#[test]
+fn rotations() {
+    let rotation = |a: &str, b: &str| {
+        Rule::NoRotations
+            .check(&words(&format!("{a} {b}")))
+            .is_some()
+    };
+
+    // every word over a small alphabet against every other one
+    let mut all = vec![];
+    for len in 1..=5 {
+        for mut n in 0..3usize.pow(len) {
+            let mut word = String::new();
+            for _ in 0..len {
+                word.push((b'a' + (n % 3) as u8) as char);
+                n /= 3;
+            }
+            all.push(word);
+        }
+    }
+
+    for a in all.iter().filter(|word| word.len() == 4) {
+        for b in &all {
+            let expected = a.len() == b.len() && format!("{a}{a}").contains(b.as_str());
+            assert_eq!(rotation(a, b), expected, "{a} {b}");
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Maze runner exits, rules and tracing.
+
+use aoc2017::day05::{self, Exit, Maze, Mutation};
+
+const SAMPLE: [i32; 5] = [0, 3, 0, 1, -3];
+
+#[test]
+fn puzzle_examples() {
+    let report = Maze::new(&SAMPLE, Mutation::Increment).run();
+    assert_eq!(report.exit, Exit::Front);
+    assert_eq!(report.steps, 5);
+    assert_eq!(report.offsets, [2, 5, 0, 1, -2]);
+
+    let report = Maze::new(&SAMPLE, Mutation::DecrementFrom(3)).run();
+    assert_eq!(report.steps, 10);
+    assert_eq!(report.offsets, [2, 3, 2, 3, -1]);
+}
+
+#[test]
+fn exits() {
+    assert_eq!(
+        Maze::new(&[1, -2], Mutation::Constant).run().exit,
+        Exit::Back
+    );
+
+    let report = Maze::new(&[0], Mutation::Constant).step_limit(100).run();
+    assert_eq!(report.exit, Exit::StepLimit);
+    assert_eq!(report.steps, 100);
+
+    let report = Maze::new(&SAMPLE, Mutation::Increment).step_limit(5).run();
+    assert_eq!(report.exit, Exit::Front);
+}
+
+#[test]
+fn trace_and_histogram() {
+    let report = Maze::new(&SAMPLE, Mutation::Increment)
+        .trace(true)
+        .histogram(true)
+        .run();
+    assert_eq!(report.trace, [0, 0, 1, 4, 1]);
+    assert_eq!(report.visits, [2, 2, 0, 0, 1]);
+
+    let report = Maze::new(&SAMPLE, Mutation::Increment).run();
+    assert!(report.trace.is_empty() && report.visits.is_empty());
+}
+
+#[test]
+fn rule_names() {
+    assert_eq!("increment".parse(), Ok(Mutation::Increment));
+    assert_eq!("decrement-from-3".parse(), Ok(Mutation::DecrementFrom(3)));
+    assert!("decrement-from-".parse::<Mutation>().is_err());
+    assert!("jump".parse::<Mutation>().is_err());
+}
+
+#[test]
+fn settled_prefix_matches_maze() {
+    // like the puzzle input, backward jumps up to the start keep crossing the front
+    // long after it has settled
+    let mut seed = 0x2017_u64;
+    for len in [1, 15, 16, 17, 100, 1000] {
+        let offsets: Vec<i32> = (0..len)
+            .map(|idx: i32| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                -((seed >> 33) as i32 % (idx + 1))
+            })
+            .collect();
+
+        let report = Maze::new(&offsets, Mutation::DecrementFrom(3)).run();
+        assert_eq!(day05::part2(&offsets), report.steps, "{offsets:?}");
+    }
+}
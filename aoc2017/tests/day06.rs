@@ -0,0 +1,91 @@
+//! Memory bank reallocation policies.
+
+use aoc2017::day06::{self, Redistribution, Simulator, TieBreak};
+
+#[test]
+fn puzzle_example() {
+    let banks = vec![0, 2, 7, 0];
+    let mut simulator = Simulator::new(&banks);
+
+    let states = [[2, 4, 1, 2], [3, 1, 2, 3], [0, 2, 3, 4], [1, 3, 4, 1]];
+    for state in states {
+        assert_eq!(simulator.step(), None);
+        assert_eq!(simulator.banks(), &state);
+    }
+    assert_eq!(simulator.step(), Some(1));
+    assert_eq!(simulator.steps(), 5);
+
+    assert_eq!(simulator.seen(&vec![3, 1, 2, 3]), Some(2));
+    assert_eq!(simulator.seen(&banks), Some(0));
+    assert_eq!(simulator.seen(&vec![1, 1, 1, 1]), None);
+}
+
+#[test]
+fn bulk_redistribution() {
+    // many rounds over all banks, the emptied one included
+    let mut banks = vec![1, 23, 0];
+    Redistribution::Forward.apply(&mut banks, 1);
+    assert_eq!(banks, [9, 7, 8]);
+
+    let mut banks = vec![0, 5, 0, 0];
+    Redistribution::Forward.apply(&mut banks, 1);
+    assert_eq!(banks, [1, 1, 2, 1]);
+
+    let mut banks = vec![0, 5, 0, 0];
+    Redistribution::Backward.apply(&mut banks, 1);
+    assert_eq!(banks, [2, 1, 1, 1]);
+
+    let mut banks = vec![0, 2, 0, 0];
+    Redistribution::Backward.apply(&mut banks, 1);
+    assert_eq!(banks, [1, 0, 0, 1]);
+
+    assert_eq!(day06::redistribute(&vec![0, 2, 7, 0]), [2, 4, 1, 2]);
+}
+
+#[test]
+fn tie_breaks() {
+    let banks = vec![3, 0, 3, 0];
+
+    let mut simulator = Simulator::new(&banks).tie_break(TieBreak::First);
+    simulator.step();
+    assert_eq!(simulator.banks(), &[0, 1, 4, 1]);
+
+    let mut simulator = Simulator::new(&banks).tie_break(TieBreak::Last);
+    simulator.step();
+    assert_eq!(simulator.banks(), &[4, 1, 0, 1]);
+
+    // the same seed picks the same banks
+    let run = |seed| {
+        let mut simulator = Simulator::new(&banks).tie_break(TieBreak::Random(seed));
+        simulator.run()
+    };
+    assert_eq!(run(7), run(7));
+}
+
+#[test]
+fn seeds_pick_differently() {
+    // eight way tie first, plenty of ties after
+    let states = |seed| {
+        let mut simulator = Simulator::new(&vec![8; 8]).tie_break(TieBreak::Random(seed));
+        (0..16)
+            .map(|_| {
+                simulator.step();
+                simulator.banks().clone()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for seed in [0, 2, 42, u64::MAX - 1] {
+        assert_ne!(states(seed), states(seed + 1), "{seed}");
+    }
+    assert_eq!(states(5), states(5));
+}
+
+#[test]
+fn policy_names() {
+    assert_eq!("last".parse(), Ok(TieBreak::Last));
+    assert_eq!("random:42".parse(), Ok(TieBreak::Random(42)));
+    assert!("random".parse::<TieBreak>().is_err());
+    assert_eq!("backward".parse(), Ok(Redistribution::Backward));
+    assert!("sideways".parse::<Redistribution>().is_err());
+}
@@ -0,0 +1,213 @@
+//! Tower diagnosis and repair.
+
+use aoc2017::day07::{self, Fix, TowerError};
+use aoc2017::registry::{self, Part};
+
+const SAMPLE: &str = include_str!("../../day07/sample.txt");
+
+fn fix(name: &str, weight: u32, corrected: u32) -> Fix {
+    Fix {
+        name: name.to_string(),
+        weight,
+        corrected,
+    }
+}
+
+#[test]
+fn puzzle_example() {
+    let tower = day07::parse(SAMPLE).unwrap();
+
+    let report = day07::diagnose(&tower);
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].name, "tknk");
+    assert_eq!(
+        report[0].towers,
+        [
+            ("ugml".to_string(), 251),
+            ("padx".to_string(), 243),
+            ("fwft".to_string(), 243)
+        ]
+    );
+    assert_eq!(report[0].fixes, [fix("ugml", 68, 60)]);
+
+    assert_eq!(day07::repair(&tower).unwrap(), fix("ugml", 68, 60));
+}
+
+#[test]
+fn every_program_below_is_unbalanced() {
+    // c is 2 too heavy, which unbalances b and the root a
+    let tower =
+        day07::parse("a (1) -> b, x\nb (1) -> c, d, e\nc (7)\nd (5)\ne (5)\nx (16)\n").unwrap();
+
+    let report = day07::diagnose(&tower);
+    let names: Vec<&str> = report.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, ["b", "a"]);
+    assert_eq!(report[0].fixes, [fix("c", 7, 5)]);
+    // b weighs 1, too little to shed 2, so only x balances a
+    assert_eq!(report[1].fixes, [fix("x", 16, 18)]);
+
+    assert_eq!(day07::repair(&tower).unwrap(), fix("c", 7, 5));
+}
+
+#[test]
+fn two_children() {
+    // the sibling y settles which of c and d is wrong
+    let tower = day07::parse("a (1) -> b, y\nb (1) -> c, d\nc (5)\nd (7)\ny (11)\n").unwrap();
+    assert_eq!(day07::diagnose(&tower)[0].fixes.len(), 2);
+    assert_eq!(day07::repair(&tower).unwrap(), fix("d", 7, 5));
+
+    // nothing does at the root
+    let tower = day07::parse("a (1) -> c, d\nc (5)\nd (7)\n").unwrap();
+    assert_eq!(
+        day07::repair(&tower).unwrap_err().to_string(),
+        "ambiguous fix, either of c (5 -> 7), d (7 -> 5)"
+    );
+    assert!(matches!(
+        day07::repair(&tower),
+        Err(TowerError::Ambiguous(fixes)) if fixes.len() == 2
+    ));
+}
+
+#[test]
+fn errors() {
+    let tower = day07::parse("a (1) -> b, c\nb (5)\nc (5)\n").unwrap();
+    assert!(matches!(day07::repair(&tower), Err(TowerError::Balanced)));
+
+    // both b and c need a new weight on their own
+    let tower = day07::parse(
+        "a (1) -> b, c\nb (1) -> d, e, f\nc (1) -> g, h, i\nd (1)\ne (1)\nf (2)\ng (1)\nh (1)\ni (2)\n",
+    )
+    .unwrap();
+    match day07::repair(&tower) {
+        Err(TowerError::SeveralFixes(mut names)) => {
+            names.sort();
+            assert_eq!(names, ["b", "c"]);
+        }
+        other => panic!("{other:?}"),
+    }
+
+    // three different weights can't be fixed with one program
+    let tower = day07::parse("a (1) -> b, c, d\nb (1)\nc (2)\nd (3)\n").unwrap();
+    assert!(day07::diagnose(&tower)[0].fixes.is_empty());
+    assert!(matches!(
+        day07::repair(&tower),
+        Err(TowerError::SeveralFixes(names)) if names == ["a"]
+    ));
+}
+
+#[test]
+fn heavy_towers() {
+    // the towers of b and c weigh more than a u32 together
+    let tower = day07::parse("a (1) -> b, c, d\nb (4294967295)\nc (4294967295)\nd (3)\n").unwrap();
+
+    assert_eq!(
+        day07::diagnose(&tower)[0].towers[..2],
+        [
+            ("b".to_string(), 4_294_967_295),
+            ("c".to_string(), 4_294_967_295)
+        ]
+    );
+    assert_eq!(day07::repair(&tower).unwrap(), fix("d", 3, 4_294_967_295));
+
+    // the odd one out would need more than a u32
+    let tower = day07::parse(
+        "a (1) -> b, c, d\nb (4294967295) -> e\nc (4294967295) -> f\nd (3)\ne (1)\nf (1)\n",
+    )
+    .unwrap();
+    assert_eq!(day07::diagnose(&tower)[0].fixes, []);
+}
+
+#[test]
+fn tree_text() {
+    let tower = day07::parse(SAMPLE).unwrap();
+
+    assert_eq!(
+        day07::render_tree(&tower, Some(1)),
+        "tknk (41) [778] unbalanced\n\
+         ├── ugml (68) [251]\n\
+         ├── padx (45) [243]\n\
+         └── fwft (72) [243]\n"
+    );
+    assert_eq!(
+        day07::render_tree(&tower, Some(0)),
+        "tknk (41) [778] unbalanced\n"
+    );
+
+    let tree = day07::render_tree(&tower, None);
+    assert_eq!(tree.lines().count(), 13);
+    assert!(tree.contains("│   └── jptl (61) [61]\n"));
+    assert!(tree.ends_with("    └── xhth (57) [57]\n"));
+}
+
+#[test]
+fn dot_graph() {
+    let tower = day07::parse(SAMPLE).unwrap();
+
+    let mut dot = vec![];
+    day07::write_dot(&mut dot, &tower).unwrap();
+    let dot = String::from_utf8(dot).unwrap();
+
+    assert!(dot.starts_with("digraph tower {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert_eq!(dot.matches(" -> ").count(), 12);
+    assert!(dot.contains(r#""tknk" [label="tknk\nweight 41\ntower 778", color=red, style=bold];"#));
+    assert!(dot.contains(
+        r#""ugml" [label="ugml\nweight 68\ntower 251\nshould weigh 60", color=orange, style=bold];"#
+    ));
+    assert!(dot.contains(r#""padx" [label="padx\nweight 45\ntower 243"];"#));
+    assert!(dot.contains(r#""tknk" -> "ugml";"#));
+}
+
+#[test]
+fn dot_escapes_names() {
+    let tower = day07::parse("say\"hi\" (1) -> back\\slash, é\nback\\slash (2)\né (2)\n").unwrap();
+
+    let mut dot = vec![];
+    day07::write_dot(&mut dot, &tower).unwrap();
+    let dot = String::from_utf8(dot).unwrap();
+
+    assert!(dot.contains(r#""say\"hi\"" [label="say\"hi\"\nweight 1\ntower 5"];"#));
+    assert!(dot.contains(r#""back\\slash" [label="back\\slash\nweight 2\ntower 2"];"#));
+    assert!(dot.contains(r#""say\"hi\"" -> "back\\slash";"#));
+    assert!(dot.contains(r#""say\"hi\"" -> "é";"#));
+}
+
+#[test]
+fn runner_reports_errors() {
+    let day = registry::find(7).unwrap();
+    let balanced = "a (1) -> b, c\nb (5)\nc (5)\n";
+
+    assert!((day.run)(balanced, &[Part::One]).is_ok());
+    let err = (day.run)(balanced, &[Part::One, Part::Two]).err().unwrap();
+    assert_eq!(err.to_string(), "the tower is already balanced");
+}
+
+#[test]
+fn malformed_towers() {
+    let error = |input: &str| day07::parse(input).err().unwrap().to_string();
+
+    assert_eq!(
+        error(""),
+        "line 1, column 1: expected program name, found nothing"
+    );
+    assert_eq!(
+        error("a (1) -> b\nb (1) -> b\n"),
+        "line 2, column 10: expected name of a program without a parent, found `b`"
+    );
+    assert_eq!(
+        error("a (1) -> b\nb (1)\na (2)\n"),
+        "line 3, column 1: expected name of a program not listed yet, found `a`"
+    );
+    assert_eq!(
+        error("a (1) -> a\n"),
+        "line 1, column 10: expected name of a program outside a cycle, found `a`"
+    );
+    assert_eq!(
+        error("r (1)\nx (1) -> y\ny (1) -> x\n"),
+        "line 2, column 10: expected name of a program outside a cycle, found `y`"
+    );
+    assert_eq!(
+        error("a (1)\nb (1)\n"),
+        "line 2, column 1: expected name of a program held by another one, found `b`"
+    );
+}
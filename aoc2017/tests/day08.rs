@@ -0,0 +1,34 @@
+//! Register machine answers and programs that never write a register.
+
+use aoc2017::day08::{self, NoRegisterError};
+use aoc2017::registry::{self, Part};
+
+const SAMPLE: &str = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10
+";
+
+#[test]
+fn puzzle_example() {
+    let program = day08::parse(SAMPLE).unwrap();
+
+    assert_eq!(day08::part1(&program), Ok(1));
+    assert_eq!(day08::part2(&program), 10);
+}
+
+#[test]
+fn no_register_written() {
+    let program = day08::parse("a inc 1 if b > 5\n").unwrap();
+
+    assert_eq!(day08::part1(&program), Err(NoRegisterError));
+    assert_eq!(day08::part2(&program), 0);
+}
+
+#[test]
+fn runner_reports_errors() {
+    let day = registry::find(8).unwrap();
+
+    let err = (day.run)("a inc 1 if b > 5\n", &[Part::One]).err().unwrap();
+    assert_eq!(err.to_string(), "no register was ever written");
+}
@@ -0,0 +1,38 @@
+//! Fractal art enhancement and rule books that miss a square.
+
+use aoc2017::day21;
+use aoc2017::registry::{self, Part};
+
+// the starting pattern has a rule, but none of its 2x2 squares after the first step do.
+const INCOMPLETE: &str = ".#./..#/### => #..#/..../..../#..#\n";
+
+#[test]
+fn missing_rule() {
+    let rules = day21::parse("../.# => ##./#../...\n").unwrap();
+
+    let err = day21::part1(&rules).unwrap_err();
+    assert_eq!(err.square, ".#./..#/###");
+    assert_eq!(
+        err.to_string(),
+        "no rule matches a 3x3 square `.#./..#/###`"
+    );
+
+    let rules = day21::parse(INCOMPLETE).unwrap();
+    assert_eq!(
+        day21::part1(&rules).unwrap_err().to_string(),
+        "no rule matches a 2x2 square `#./..`"
+    );
+}
+
+#[test]
+fn runner_reports_errors() {
+    let day = registry::find(21).unwrap();
+
+    let err = (day.run)("../.# => ##./#../...\n", &[Part::One, Part::Two])
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "no rule matches a 3x3 square `.#./..#/###`"
+    );
+}
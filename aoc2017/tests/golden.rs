@@ -0,0 +1,101 @@
+//! Checks every day against the answers recorded in `dayNN/answers.toml`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc2017::answers;
+use aoc2017::registry::{self, Day};
+
+fn day_dir(day: &Day) -> PathBuf {
+    // day directories are siblings of the library crate.
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(day.dir())
+}
+
+/// Mismatches of a single day, one line per input file and part.
+fn check(day: &Day) -> Vec<String> {
+    let dir = day_dir(day);
+    let manifest = dir.join(answers::FILE_NAME);
+    let label = format!("day {:02}", day.number);
+
+    let Ok(manifest_content) = fs::read_to_string(&manifest) else {
+        // nothing recorded yet.
+        return vec![];
+    };
+    let fixtures = match answers::parse(&manifest_content) {
+        Ok(fixtures) => fixtures,
+        Err(err) => return vec![format!("{label}: {}: {err}", manifest.display())],
+    };
+
+    let mut mismatches = vec![];
+
+    for fixture in fixtures {
+        let label = format!("{label} {}", fixture.name);
+
+        let input = match fs::read_to_string(dir.join(fixture.file_name())) {
+            Ok(input) => input,
+            Err(err) => {
+                mismatches.push(format!(
+                    "{label}: can't read {}: {err}",
+                    fixture.file_name()
+                ));
+                continue;
+            }
+        };
+
+        let report = match (day.run)(&input, &fixture.parts()) {
+            Ok(report) => report,
+            Err(err) => {
+                mismatches.push(format!("{label}: {err}"));
+                continue;
+            }
+        };
+
+        for (part, expected) in &fixture.answers {
+            match report.answer(*part) {
+                Some(answer) if answer.answer == *expected => {}
+                Some(answer) => mismatches.push(format!(
+                    "{label} part {part}: expected {expected}, got {}",
+                    answer.answer
+                )),
+                None => mismatches.push(format!(
+                    "{label} part {part}: expected {expected}, got no answer"
+                )),
+            }
+        }
+    }
+
+    mismatches
+}
+
+#[test]
+fn answers_match() {
+    let mismatches: Vec<String> = registry::DAYS.iter().flat_map(check).collect();
+
+    assert!(
+        mismatches.is_empty(),
+        "{} mismatches:\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn manifest_format() {
+    let fixtures =
+        answers::parse("# comment\n[sample]\npart1 = \"18\"\n\n[input]\npart2=\"x y\"\n").unwrap();
+
+    assert_eq!(fixtures.len(), 2);
+    assert_eq!(fixtures[0].file_name(), "sample.txt");
+    assert_eq!(
+        fixtures[1].answers,
+        [(registry::Part::Two, "x y".to_string())]
+    );
+
+    let err = answers::parse("part1 = \"1\"\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 1));
+
+    let err = answers::parse("[input]\npart3 = \"1\"\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "part3"));
+}
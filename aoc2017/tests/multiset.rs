@@ -0,0 +1,74 @@
+//! Counting collections.
+
+use std::collections::HashSet;
+
+use aoc2017::multiset::{LetterMultiset, Multiset};
+
+#[test]
+fn counting() {
+    let mut letters: Multiset<char> = "banana".chars().collect();
+
+    assert_eq!(letters.count(&'a'), 3);
+    assert_eq!(letters.count(&'x'), 0);
+    assert_eq!(letters.len(), 6);
+
+    assert!(letters.remove(&'b'));
+    assert!(!letters.remove(&'b'));
+    assert_eq!(letters, "anana".chars().collect());
+}
+
+#[test]
+fn equality_and_hash() {
+    let words = ["listen", "silent", "enlist", "google", "gogole"];
+
+    let keys: HashSet<Multiset<char>> = words.iter().map(|word| word.chars().collect()).collect();
+    assert_eq!(keys.len(), 2);
+
+    let keys: HashSet<LetterMultiset> = words
+        .iter()
+        .map(|word| LetterMultiset::from_bytes(word.as_bytes()).unwrap())
+        .collect();
+    assert_eq!(keys.len(), 2);
+}
+
+#[test]
+fn set_operations() {
+    let a: Multiset<u8> = [1, 1, 2, 3].into_iter().collect();
+    let b: Multiset<u8> = [1, 2, 2, 4].into_iter().collect();
+
+    assert_eq!(a.union(&b), [1, 1, 2, 2, 3, 4].into_iter().collect());
+    assert_eq!(a.intersection(&b), [1, 2].into_iter().collect());
+    assert!(a.intersection(&b).is_subset(&a));
+    assert!(a.union(&b).is_superset(&b));
+    assert!(!a.is_subset(&b));
+
+    let letters = |bytes: &[u8]| LetterMultiset::from_bytes(bytes).unwrap();
+    let a = letters(b"aabc");
+    let b = letters(b"abbd");
+
+    assert_eq!(a.union(&b), letters(b"aabbcd"));
+    assert_eq!(a.intersection(&b), letters(b"ab"));
+    assert!(letters(b"ab").is_subset(&a));
+    assert_eq!(
+        a.iter().collect::<Vec<_>>(),
+        [(b'a', 2), (b'b', 1), (b'c', 1)]
+    );
+}
+
+#[test]
+fn letters_only() {
+    let mut letters = LetterMultiset::new();
+    assert!(letters.insert(b'z'));
+    assert!(!letters.insert(b'Z'));
+    assert!(!letters.insert(b'{'));
+    assert!(!letters.remove(b'a'));
+    assert_eq!(letters.count(b'z'), 1);
+    assert_eq!(letters.count(b'?'), 0);
+    assert!(letters.remove(b'z'));
+    assert!(letters.is_empty());
+
+    assert_eq!(LetterMultiset::from_bytes(b"ab c"), None);
+    assert_eq!(LetterMultiset::from_bytes("é".as_bytes()), None);
+    assert_eq!(LetterMultiset::from_bytes(&[b'a'; 255]).unwrap().len(), 255);
+    assert_eq!(LetterMultiset::from_bytes(&[b'a'; 256]), None);
+}
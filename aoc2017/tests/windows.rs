@@ -0,0 +1,41 @@
+//! Sliding windows over iterators.
+
+use aoc2017::windows::WindowsExt;
+
+#[test]
+fn like_slice_windows() {
+    let items = [1, 2, 3, 4, 5];
+
+    let pairs: Vec<[i32; 2]> = items.iter().copied().windows::<2>().collect();
+    let expected: Vec<[i32; 2]> = items.windows(2).map(|w| [w[0], w[1]]).collect();
+    assert_eq!(pairs, expected);
+
+    let triples: Vec<[i32; 3]> = items.into_iter().windows().collect();
+    assert_eq!(triples, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+
+    let whole: Vec<[i32; 5]> = items.into_iter().windows().collect();
+    assert_eq!(whole, [items]);
+}
+
+#[test]
+fn too_short() {
+    assert_eq!([1, 2].into_iter().windows::<3>().next(), None);
+    assert_eq!(Vec::<u8>::new().into_iter().windows::<1>().next(), None);
+}
+
+#[test]
+fn lazy_and_fused_by_source() {
+    // an endless source is fine, only as many items as needed are taken
+    let windows: Vec<[u64; 2]> = (0..).windows().take(3).collect();
+    assert_eq!(windows, [[0, 1], [1, 2], [2, 3]]);
+
+    let mut windows = "ab".chars().windows::<2>();
+    assert_eq!(windows.next(), Some(['a', 'b']));
+    assert_eq!(windows.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn empty_window() {
+    let _ = [1].into_iter().windows::<0>();
+}
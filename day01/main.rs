@@ -1,46 +1,42 @@
-#![feature(iter_map_windows)]
+use aoc2017::day01::{self, Day01};
+use std::env;
+use std::error;
+use std::fs::File;
 
-use std::cmp::Ordering;
-
-fn input() -> &'static [u8] {
-    include_bytes!("input.txt")
-}
+fn main() -> Result<(), Box<dyn error::Error>> {
+    println!("--- Day 1: Inverse Captcha ---");
 
-fn part1(seq: &[u8]) -> u32 {
-    seq[..seq.len() - 1]
-        .iter()
-        .map_windows(|[a, b]| match a.cmp(b) {
-            Ordering::Equal => (*a - 48) as u32,
-            _ => 0,
-        })
-        .sum::<u32>()
-        + if seq[0] == seq[seq.len() - 2] {
-            (seq[0] - 48) as u32
-        } else {
-            0
+    // `--stream` after the input file solves it without reading it whole into memory,
+    // part 2 reads the file twice, so stdin can't be streamed.
+    if env::args().nth(2).as_deref() == Some("--stream") {
+        let input_file = env::args()
+            .nth(1)
+            .ok_or("no input file as cli argument is provided")?;
+        if input_file == "-" {
+            Err("--stream reads the input twice, it needs a file instead of stdin")?;
         }
-}
 
-fn part2(seq: &[u8]) -> u32 {
-    let mut result = 0;
-    let seq = &seq[..seq.len() - 1];
-    // println!("{}", seq.len());
-    let shift = seq.len() / 2;
-
-    for (idx, byte) in seq.iter().enumerate() {
-        let digit = byte - 48; // ascii
-        let next_digit = seq[(idx + shift) % seq.len()] - 48;
-        if digit == next_digit {
-            result += digit as u32;
-        }
+        println!(
+            "{}",
+            day01::stream_next_digit_sum(File::open(&input_file)?)?
+        );
+        println!("{}", day01::stream_half_way_sum(File::open(&input_file)?)?);
+
+        return Ok(());
     }
 
-    result
-}
+    let input_data = aoc2017::read_input::<Day01>()?;
 
-fn main() {
-    println!("--- Day 1: Inverse Captcha ---");
+    // `--sweep` after the input file prints sums for every offset.
+    if env::args().nth(2).as_deref() == Some("--sweep") {
+        for (offset, sum) in day01::sweep(&input_data) {
+            println!("{offset:>8}  {sum}");
+        }
+        return Ok(());
+    }
+
+    println!("{}", day01::part1(&input_data));
+    println!("{}", day01::part2(&input_data));
 
-    println!("{:?}", part1(input()));
-    println!("{:?}", part2(input()));
+    Ok(())
 }
@@ -1,65 +1,61 @@
+use aoc2017::day02::{self, Checksum, Day02, Reader};
 use std::env;
 use std::error;
 use std::fs;
+use std::process;
 
-fn parse_line(line: &str) -> Vec<u32> {
-    line.split_whitespace()
-        .map(|num| num.parse().unwrap())
-        .collect()
-}
-
-fn read_input(filename: &str) -> Result<Vec<Vec<u32>>, Box<dyn error::Error>> {
-    let spreadsheet = fs::read_to_string(filename)?
-        .lines()
-        .map(parse_line)
-        .collect();
+const USAGE: &str = "usage: day02 <input> [--checksum <name>] [--delimiter <char>] [--header]";
 
-    Ok(spreadsheet)
-}
-
-fn part1(table: &[Vec<u32>]) -> u32 {
-    table
-        .iter()
-        // .inspect(|row| println!("{:?}", row))
-        .map(|row| {
-            let (min, max) = row
-                .iter()
-                .fold((row[0], row[0]), |(min, max), &x| (min.min(x), max.max(x)));
-            max - min
-        })
-        .sum()
-}
+fn try_main() -> Result<(), Box<dyn error::Error>> {
+    println!("--- Day 2: Corruption Checksum ---");
 
-fn get_goal(row: &Vec<u32>) -> u32 {
-    for a in row {
-        for b in row {
-            if a > b && a.is_multiple_of(*b) {
-                return a / b;
+    // options after the input file read it as a spreadsheet export of signed 64-bit cells
+    // and solve it with a single checksum.
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() > 1 {
+        let mut reader = Reader::new();
+        let mut checksum = Checksum::Range;
+
+        let mut options = args[1..].iter();
+        while let Some(option) = options.next() {
+            match option.as_str() {
+                "--checksum" => checksum = options.next().ok_or(USAGE)?.parse()?,
+                "--delimiter" => {
+                    let delimiter = match options.next().ok_or(USAGE)?.as_str() {
+                        "\\t" | "tab" => '\t',
+                        delimiter if delimiter.chars().count() == 1 => {
+                            delimiter.chars().next().unwrap()
+                        }
+                        _ => Err(USAGE)?,
+                    };
+                    reader = reader.delimiter(delimiter);
+                }
+                "--header" => reader = reader.skip_header(true),
+                _ => Err(USAGE)?,
             }
         }
-    }
-    unreachable!("must be always a goal");
-}
 
-fn part2(table: &[Vec<u32>]) -> u32 {
-    table.iter().map(get_goal).sum()
-}
+        let input = fs::read_to_string(&args[0])?;
+        let table: Vec<Vec<i64>> = reader
+            .read(&input)
+            .map_err(|err| format!("{}: {err}", args[0]))?;
 
-fn main() -> Result<(), Box<dyn error::Error>> {
-    println!("--- Day 2: Corruption Checksum ---");
-
-    let input_file = env::args()
-        .nth(1)
-        .ok_or("no input file as cli argument is provided")?;
-
-    // println!("{input_file}");
-
-    let input_data = read_input(&input_file)?;
+        println!("{}", day02::checksum(&table, &checksum)?);
+        return Ok(());
+    }
 
-    // println!("{:?}", input_data);
+    let input_data = aoc2017::read_input::<Day02>()?;
 
-    println!("{}", part1(&input_data));
-    println!("{}", part2(&input_data));
+    println!("{}", day02::part1(&input_data)?);
+    println!("{}", day02::part2(&input_data)?);
 
     Ok(())
 }
+
+fn main() {
+    // `Result` from `main` prints errors with `Debug`, checksum errors read better with `Display`.
+    if let Err(err) = try_main() {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -1,91 +1,26 @@
-use std::collections::HashMap;
+use aoc2017::day04::{self, Day04, Policy, Rule};
 use std::env;
 use std::error;
-use std::fs;
-use std::hash::Hash;
 
-type Passphrase = Vec<String>;
-
-fn read_input(filename: &str) -> Result<Vec<Passphrase>, Box<dyn error::Error>> {
-    let passphrases = fs::read_to_string(filename)?
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|word| word.to_string())
-                .collect()
-        })
-        .collect();
-
-    Ok(passphrases)
-}
-
-fn freqs<T: Eq + Hash + Clone>(items: impl Iterator<Item = T>) -> HashMap<T, u32> {
-    let mut freqs = HashMap::<T, u32>::new();
-
-    for item in items {
-        let freq = freqs.entry(item).or_insert(0);
-        *freq += 1;
-    }
-
-    freqs
-}
-
-fn is_valid_1(pp: &&Passphrase) -> bool {
-    freqs::<String>(pp.iter().cloned())
-        .iter()
-        .all(|(_, freq)| *freq == 1)
-}
-
-fn part1(pps: &[Passphrase]) -> usize {
-    pps.iter().filter(is_valid_1).count()
-}
+fn main() -> Result<(), Box<dyn error::Error>> {
+    println!("--- Day 4: High-Entropy Passphrases ---");
 
-fn equal_freqs<T: Eq + Hash>(freq1: &HashMap<T, u32>, freq2: &HashMap<T, u32>) -> bool {
-    freq1.iter().all(|(ch, freq)| {
-        let value = freq2.get(ch);
-        value == Some(freq)
-    }) && freq2.iter().all(|(ch, freq)| {
-        let value = freq1.get(ch);
-        value == Some(freq)
-    })
-}
+    let input_data = aoc2017::read_input::<Day04>()?;
 
-fn is_valid_2(pp: &&Passphrase) -> bool {
-    let word_freqs = freqs::<String>(pp.iter().cloned());
-    let uniq_words = word_freqs.iter().all(|(_, freq)| *freq == 1);
-    if !uniq_words {
-        return false;
-    }
-    let char_freqs: Vec<_> = pp.iter().map(|word| freqs::<char>(word.chars())).collect();
+    // `--report` after the input file explains why passphrases are rejected in part 2.
+    if env::args().nth(2).as_deref() == Some("--report") {
+        let policy = Policy::new().rule(Rule::UniqueWords).rule(Rule::NoAnagrams);
 
-    // anagrams have the same char frequencies
-    for (idx1, freq1) in char_freqs.iter().enumerate() {
-        for (idx2, freq2) in char_freqs.iter().enumerate() {
-            if idx1 != idx2 && equal_freqs::<char>(freq1, freq2) {
-                return false;
+        for (idx, passphrase) in input_data.iter().enumerate() {
+            if let Err(violation) = policy.check(passphrase) {
+                println!("line {}: {violation}", idx + 1);
             }
         }
+        return Ok(());
     }
-    true
-}
-
-fn part2(pps: &[Passphrase]) -> usize {
-    pps.iter().filter(is_valid_2).count()
-}
-
-fn main() -> Result<(), Box<dyn error::Error>> {
-    println!("--- Day 4: High-Entropy Passphrases ---");
-
-    let input_file = env::args()
-        .nth(1)
-        .ok_or("no input file as cli argument is provided")?;
-
-    let input_data = read_input(&input_file)?;
-
-    // println!("{input_data:?}");
 
-    println!("{}", part1(&input_data));
-    println!("{}", part2(&input_data));
+    println!("{}", day04::part1(&input_data));
+    println!("{}", day04::part2(&input_data));
 
     Ok(())
 }
@@ -1,170 +1,13 @@
-use std::collections::VecDeque;
-use std::env;
+use aoc2017::day14::{self, Day14};
 use std::error;
-use std::fs;
-
-#[derive(Debug)]
-struct BitField128 {
-    arr: Vec<u8>,
-}
-
-impl BitField128 {
-    fn with_vec(numbers: Vec<u8>) -> Self {
-        assert!(numbers.len() == 2048);
-
-        BitField128 { arr: numbers }
-    }
-
-    fn is_used(&self, col: usize, row: usize) -> bool {
-        let idx = row * 128 + col;
-        let n_idx = idx / 8;
-        let shift = 7 - idx % 8;
-
-        self.arr[n_idx] & (1 << shift) != 0
-    }
-
-    fn set_unused(&mut self, col: usize, row: usize) {
-        let idx = row * 128 + col;
-        let n_idx = idx / 8;
-        let shift = 7 - idx % 8;
-
-        self.arr[n_idx] &= !(1 << shift);
-    }
-}
-
-fn read_input(filename: &str) -> Result<String, Box<dyn error::Error>> {
-    Ok(fs::read_to_string(filename)?.trim_end().to_string())
-}
-
-#[inline(always)]
-fn reverse(list: &mut [u8], position: usize, length: usize) {
-    let end_pos = position + length - 1;
-
-    let mut l = position % list.len();
-    let mut h = end_pos % list.len();
-
-    while l != (position + length / 2) % list.len() {
-        if l != h {
-            // swap
-            list[l] ^= list[h];
-            list[h] ^= list[l];
-            list[l] ^= list[h]
-        }
-        l += 1;
-        l %= list.len();
-        if h == 0 {
-            h = list.len();
-        }
-        h -= 1;
-    }
-}
-
-fn calculate_sparse_hash(input: &str, idx: usize) -> Vec<u8> {
-    let mut to_hash = input.to_string();
-    to_hash.push('-');
-    to_hash.push_str(&idx.to_string());
-
-    let mut lengths: Vec<usize> = to_hash
-        .as_bytes()
-        .iter()
-        .copied()
-        .map(|v| v as usize)
-        .collect();
-
-    lengths.extend([17, 31, 73, 47, 23]);
-
-    let mut hash = vec![];
-    (0..=255).for_each(|num| hash.push(num as u8));
-
-    let mut position = 0;
-    let mut skip = 0;
-
-    // 64 rounds
-    (0..64).for_each(|_| {
-        for length in &lengths {
-            reverse(&mut hash, position, *length);
-            position += (*length + skip) % hash.len();
-            skip += 1;
-        }
-    });
-
-    hash
-}
-
-fn part1(input: &str) -> u32 {
-    // Given your actual key string, how many squares are used?
-    (0..128)
-        .map(|idx| {
-            calculate_sparse_hash(input, idx)
-                .chunks(16)
-                // densify [256] -> [16]
-                .map(|chunk| chunk.iter().copied().reduce(|acc, e| acc ^ e).unwrap())
-                .map(|num| num.count_ones())
-                .sum::<u32>()
-        })
-        .sum()
-}
-
-fn region_mark(disk: &mut BitField128, col: usize, row: usize) {
-    let mut to_process = VecDeque::new();
-
-    to_process.push_back((col, row));
-
-    while let Some((col, row)) = to_process.pop_front() {
-        disk.set_unused(col, row);
-
-        for (dr, dc) in [(0, 1), (1, 0), (0, !0), (!0, 0)] {
-            // https://t.me/bminaiev_blog/59
-            let nrow = row.overflowing_add(dr).0;
-            let ncol = col.overflowing_add(dc).0;
-
-            if nrow < 128 && ncol < 128 && disk.is_used(ncol, nrow) {
-                to_process.push_back((ncol, nrow));
-            }
-        }
-    }
-}
-
-fn part2(input: &str) -> u32 {
-    // How many regions are present given your key string?
-
-    let mut disk = BitField128::with_vec(
-        (0..128)
-            .flat_map(|idx| {
-                calculate_sparse_hash(input, idx)
-                    .chunks(16)
-                    // densify [256] -> [16]
-                    .map(|chunk| chunk.iter().copied().reduce(|acc, e| acc ^ e).unwrap())
-                    .collect::<Vec<u8>>()
-            })
-            .collect(),
-    );
-
-    let mut regions_count = 0;
-
-    for row in 0..128 {
-        for col in 0..128 {
-            if disk.is_used(col, row) {
-                regions_count += 1;
-                region_mark(&mut disk, col, row);
-            }
-        }
-    }
-
-    regions_count
-}
 
 fn main() -> Result<(), Box<dyn error::Error>> {
     println!("--- Day14: Disk Defragmentation ---");
 
-    let input_file = env::args()
-        .nth(1)
-        .ok_or("no input file as cli argument is provided")?;
-
-    let input_data = read_input(&input_file)?;
+    let input_data = aoc2017::read_input::<Day14>()?;
 
-    println!("{}", part1(&input_data));
-    println!("{}", part2(&input_data));
+    println!("{}", day14::part1(&input_data));
+    println!("{}", day14::part2(&input_data));
 
     Ok(())
 }
@@ -1,36 +1,13 @@
-fn part1(steps: usize) -> u32 {
-    let mut buf = vec![0];
-    let mut idx = 0;
+use aoc2017::day17::{self, Day17};
+use std::error;
 
-    (1..=2017).for_each(|el| {
-        idx = (idx + steps) % buf.len() + 1;
-        buf.insert(idx, el);
-    });
-
-    buf[idx + 1]
-}
-
-fn part2(steps: usize) -> u32 {
-    let mut result = 0;
-    let mut idx = 0;
-    let mut buf_len = 1;
-
-    (1..=50_000_000).for_each(|el| {
-        idx = (idx + steps) % buf_len + 1;
-        if idx == 1 {
-            result = el;
-        }
-        buf_len += 1;
-    });
-
-    result
-}
-
-fn main() {
+fn main() -> Result<(), Box<dyn error::Error>> {
     println!("--- Day17: Spinlock ---");
 
-    let input_data = 370;
+    let input_data = aoc2017::read_input::<Day17>()?;
+
+    println!("{}", day17::part1(input_data));
+    println!("{}", day17::part2(input_data));
 
-    println!("{}", part1(input_data));
-    println!("{}", part2(input_data));
+    Ok(())
 }